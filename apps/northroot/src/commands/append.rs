@@ -1,5 +1,6 @@
 //! Append command implementation.
 
+use super::import::prepare_line;
 use crate::path;
 use northroot_canonical::{compute_event_id, parse_json_strict, Canonicalizer, ProfileId};
 use northroot_journal::{JournalWriter, WriteOptions};
@@ -11,6 +12,8 @@ pub fn run(
     input: Option<String>,
     strict: bool,
     sync: bool,
+    batch: bool,
+    ndjson: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
     // Validate journal path (allow non-existent files for creation)
     let journal_path = if std::path::Path::new(&journal).exists() {
@@ -60,19 +63,91 @@ pub fn run(
         buffer
     };
 
-    let mut event: Value =
-        parse_json_strict(&json_str).map_err(|e| format!("Invalid JSON: {}", e))?;
-
     // Initialize canonicalizer
     let profile = ProfileId::parse("northroot-canonical-v1")
         .map_err(|e| format!("Invalid profile ID: {}", e))?;
     let canonicalizer = Canonicalizer::new(profile);
 
+    // Prepare every event before opening the journal so a batch or stream
+    // with any invalid element writes nothing.
+    let events = if ndjson {
+        json_str
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                prepare_line(line, strict, &canonicalizer)
+                    .map_err(|e| format!("Line {}: {}", index + 1, e))
+            })
+            .collect::<Result<Vec<_>, _>>()?
+    } else {
+        let input: Value =
+            parse_json_strict(&json_str).map_err(|e| format!("Invalid JSON: {}", e))?;
+        if batch {
+            let Value::Array(items) = input else {
+                return Err("Batch input must be a JSON array of events".into());
+            };
+            items
+                .into_iter()
+                .enumerate()
+                .map(|(index, event)| {
+                    prepare_event(event, strict, &canonicalizer)
+                        .map_err(|e| format!("Batch element {}: {}", index, e))
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        } else {
+            vec![prepare_event(input, strict, &canonicalizer)?]
+        }
+    };
+
+    // Open journal for writing
+    let write_options = WriteOptions {
+        sync,
+        create: true,
+        append: true,
+        write_footer: false,
+        frame_crc: false,
+        index: false,
+        compress: None,
+    };
+
+    let mut writer = JournalWriter::open(&journal_path, write_options).map_err(|e| {
+        let sanitized = path::sanitize_path_for_error(&journal_path);
+        format!("Failed to open journal file: {}: {}", sanitized, e)
+    })?;
+
+    // Append events
+    for event in &events {
+        writer.append_event(event).map_err(|e| {
+            let sanitized = path::sanitize_path_for_error(&journal_path);
+            format!("Failed to append event to journal: {}: {}", sanitized, e)
+        })?;
+    }
+
+    // Finish writing (closes file)
+    writer.finish().map_err(|e| {
+        let sanitized = path::sanitize_path_for_error(&journal_path);
+        format!("Failed to finish writing journal: {}: {}", sanitized, e)
+    })?;
+
+    if ndjson {
+        println!("Appended {} events", events.len());
+    }
+
+    Ok(())
+}
+
+/// Checks a claimed `event_id` in strict mode and sets the computed one.
+pub(super) fn prepare_event(
+    mut event: Value,
+    strict: bool,
+    canonicalizer: &Canonicalizer,
+) -> Result<Value, Box<dyn std::error::Error>> {
     // If strict mode, check existing event_id before computing
     if strict {
         if let Some(existing_id) = event.get("event_id") {
             // Compute event_id and compare
-            let computed_id = compute_event_id(&event, &canonicalizer)
+            let computed_id = compute_event_id(&event, canonicalizer)
                 .map_err(|e| format!("Event ID computation failed: {}", e))?;
 
             let existing_id_str = serde_json::to_string(existing_id)?;
@@ -90,37 +165,13 @@ pub fn run(
     }
 
     // Compute event_id (will be used if not already present or not in strict mode)
-    let event_id = compute_event_id(&event, &canonicalizer)
+    let event_id = compute_event_id(&event, canonicalizer)
         .map_err(|e| format!("Event ID computation failed: {}", e))?;
 
     // Add event_id to event (overwrites if already present, which is fine)
     event["event_id"] = serde_json::to_value(&event_id)?;
 
-    // Open journal for writing
-    let write_options = WriteOptions {
-        sync,
-        create: true,
-        append: true,
-    };
-
-    let mut writer = JournalWriter::open(&journal_path, write_options).map_err(|e| {
-        let sanitized = path::sanitize_path_for_error(&journal_path);
-        format!("Failed to open journal file: {}: {}", sanitized, e)
-    })?;
-
-    // Append event
-    writer.append_event(&event).map_err(|e| {
-        let sanitized = path::sanitize_path_for_error(&journal_path);
-        format!("Failed to append event to journal: {}: {}", sanitized, e)
-    })?;
-
-    // Finish writing (closes file)
-    writer.finish().map_err(|e| {
-        let sanitized = path::sanitize_path_for_error(&journal_path);
-        format!("Failed to finish writing journal: {}: {}", sanitized, e)
-    })?;
-
-    Ok(())
+    Ok(event)
 }
 
 #[cfg(test)]
@@ -157,6 +208,8 @@ mod tests {
             Some(event_file.to_str().unwrap().to_string()),
             false,
             false,
+            false,
+            false,
         );
         assert!(result.is_ok(), "Append failed: {:?}", result.err());
 
@@ -200,6 +253,8 @@ mod tests {
             Some(event_file1.to_str().unwrap().to_string()),
             false,
             false,
+            false,
+            false,
         )
         .unwrap();
 
@@ -211,6 +266,8 @@ mod tests {
             Some(event_file2.to_str().unwrap().to_string()),
             false,
             false,
+            false,
+            false,
         )
         .unwrap();
 
@@ -240,6 +297,8 @@ mod tests {
             Some(invalid_file.to_str().unwrap().to_string()),
             false,
             false,
+            false,
+            false,
         );
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Invalid JSON"));
@@ -271,6 +330,8 @@ mod tests {
             Some(event_file.to_str().unwrap().to_string()),
             true, // strict mode
             false,
+            false,
+            false,
         );
         assert!(result.is_err());
         assert!(result
@@ -297,6 +358,8 @@ mod tests {
             Some(duplicate_file.to_str().unwrap().to_string()),
             true,
             false,
+            false,
+            false,
         );
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("duplicate key"));
@@ -328,6 +391,8 @@ mod tests {
             Some(event_file.to_str().unwrap().to_string()),
             false,
             false,
+            false,
+            false,
         );
         assert!(result.is_ok(), "Append failed: {:?}", result.err());
 
@@ -348,4 +413,161 @@ mod tests {
             "Path canonicalization should eliminate traversal sequences"
         );
     }
+
+    fn batch_event(name: &str) -> Value {
+        json!({
+            "event_type": name,
+            "event_version": "1",
+            "occurred_at": "2024-01-01T00:00:00Z",
+            "principal_id": "service:test",
+            "canonical_profile_id": "northroot-canonical-v1"
+        })
+    }
+
+    #[test]
+    fn test_append_batch_writes_events_in_order() {
+        let temp = TempDir::new().unwrap();
+        let journal_path = temp.path().join("test.nrj");
+        let batch_file = temp.path().join("batch.json");
+        let batch = json!([batch_event("first"), batch_event("second")]);
+        fs::write(&batch_file, batch.to_string()).unwrap();
+
+        run(
+            journal_path.to_str().unwrap().to_string(),
+            Some(batch_file.to_str().unwrap().to_string()),
+            true,
+            false,
+            true,
+            false,
+        )
+        .unwrap();
+
+        let mut reader =
+            JournalReader::open(&journal_path, northroot_journal::ReadMode::Strict).unwrap();
+        assert_eq!(reader.read_event().unwrap().unwrap()["event_type"], "first");
+        assert_eq!(
+            reader.read_event().unwrap().unwrap()["event_type"],
+            "second"
+        );
+        assert!(reader.read_event().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_append_batch_with_bad_event_id_writes_nothing() {
+        let temp = TempDir::new().unwrap();
+        let journal_path = temp.path().join("test.nrj");
+        JournalWriter::open(&journal_path, WriteOptions::default())
+            .unwrap()
+            .finish()
+            .unwrap();
+
+        let mut tampered = batch_event("third");
+        tampered["event_id"] = json!({"alg": "sha-256", "b64": "wrong_id"});
+        let batch = json!([batch_event("first"), batch_event("second"), tampered]);
+        let batch_file = temp.path().join("batch.json");
+        fs::write(&batch_file, batch.to_string()).unwrap();
+
+        let result = run(
+            journal_path.to_str().unwrap().to_string(),
+            Some(batch_file.to_str().unwrap().to_string()),
+            true,
+            false,
+            true,
+            false,
+        );
+
+        let err = result.unwrap_err().to_string();
+        assert!(err.contains("Batch element 2"));
+        assert!(err.contains("Event ID mismatch"));
+        let mut reader =
+            JournalReader::open(&journal_path, northroot_journal::ReadMode::Strict).unwrap();
+        assert!(reader.read_event().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_append_batch_requires_array() {
+        let temp = TempDir::new().unwrap();
+        let journal_path = temp.path().join("test.nrj");
+        let event_file = temp.path().join("event.json");
+        fs::write(&event_file, batch_event("single").to_string()).unwrap();
+
+        let result = run(
+            journal_path.to_str().unwrap().to_string(),
+            Some(event_file.to_str().unwrap().to_string()),
+            false,
+            false,
+            true,
+            false,
+        );
+
+        assert!(result.unwrap_err().to_string().contains("JSON array"));
+        assert!(!journal_path.exists());
+    }
+
+    #[test]
+    fn test_append_ndjson_appends_each_line() {
+        let temp = TempDir::new().unwrap();
+        let journal_path = temp.path().join("test.nrj");
+        let input_file = temp.path().join("events.ndjson");
+        fs::write(
+            &input_file,
+            format!(
+                "{}\n\n{}\n{}\n",
+                batch_event("first"),
+                batch_event("second"),
+                batch_event("third")
+            ),
+        )
+        .unwrap();
+
+        run(
+            journal_path.to_str().unwrap().to_string(),
+            Some(input_file.to_str().unwrap().to_string()),
+            true,
+            false,
+            false,
+            true,
+        )
+        .unwrap();
+
+        let mut reader =
+            JournalReader::open(&journal_path, northroot_journal::ReadMode::Strict).unwrap();
+        let canonicalizer = Canonicalizer::new(ProfileId::parse("northroot-canonical-v1").unwrap());
+        for name in ["first", "second", "third"] {
+            let event = reader.read_event().unwrap().unwrap();
+            assert_eq!(event["event_type"], name);
+            let event_id = compute_event_id(&batch_event(name), &canonicalizer).unwrap();
+            assert_eq!(event["event_id"], serde_json::to_value(event_id).unwrap());
+        }
+        assert!(reader.read_event().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_append_ndjson_with_invalid_line_writes_nothing() {
+        let temp = TempDir::new().unwrap();
+        let journal_path = temp.path().join("test.nrj");
+        let input_file = temp.path().join("events.ndjson");
+        fs::write(
+            &input_file,
+            format!(
+                "{}\n{{\"event_type\": \n{}\n",
+                batch_event("first"),
+                batch_event("third")
+            ),
+        )
+        .unwrap();
+
+        let result = run(
+            journal_path.to_str().unwrap().to_string(),
+            Some(input_file.to_str().unwrap().to_string()),
+            false,
+            false,
+            false,
+            true,
+        );
+
+        let err = result.unwrap_err().to_string();
+        assert!(err.starts_with("Line 2: Invalid JSON"), "{}", err);
+        assert!(!journal_path.exists());
+    }
 }
@@ -1,9 +1,13 @@
 //! Canonicalize command implementation.
 
-use northroot_canonical::{parse_json_strict, Canonicalizer, ProfileId};
+use crate::output::encode_hex;
+use northroot_canonical::{
+    compute_event_id, event_id_preimage, parse_json_strict, Canonicalizer, ProfileId,
+};
+use serde_json::Value;
 use std::io::{self, Read};
 
-pub fn run(input: Option<String>) -> Result<(), Box<dyn std::error::Error>> {
+pub fn run(input: Option<String>, debug: bool) -> Result<(), Box<dyn std::error::Error>> {
     let profile = ProfileId::parse("northroot-canonical-v1")
         .map_err(|e| format!("Invalid profile ID: {}", e))?;
     let canonicalizer = Canonicalizer::new(profile);
@@ -20,6 +24,11 @@ pub fn run(input: Option<String>) -> Result<(), Box<dyn std::error::Error>> {
 
     let value = parse_json_strict(&json_str).map_err(|e| format!("Invalid JSON: {}", e))?;
 
+    if debug {
+        print!("{}", debug_report(&value, &canonicalizer)?);
+        return Ok(());
+    }
+
     let result = canonicalizer
         .canonicalize(&value)
         .map_err(|e| format!("Canonicalization failed: {}", e))?;
@@ -27,3 +36,66 @@ pub fn run(input: Option<String>) -> Result<(), Box<dyn std::error::Error>> {
     println!("{}", String::from_utf8_lossy(&result.bytes));
     Ok(())
 }
+
+/// Formats the event_id preimage as hex and UTF-8 alongside the computed event_id.
+///
+/// The preimage is the canonical form without any `event_id` field, which is
+/// what the event_id is computed over. Interop partners diff this against
+/// their own canonicalizer's output.
+fn debug_report(
+    value: &Value,
+    canonicalizer: &Canonicalizer,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let preimage = event_id_preimage(value, canonicalizer)
+        .map_err(|e| format!("Canonicalization failed: {}", e))?;
+    let event_id = compute_event_id(value, canonicalizer)
+        .map_err(|e| format!("Event ID computation failed: {}", e))?;
+
+    Ok(format!(
+        "hex: {}\nutf8: {}\nevent_id: {}\n",
+        encode_hex(&preimage),
+        String::from_utf8_lossy(&preimage),
+        event_id.b64
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn debug_report_shows_canonical_hex_utf8_and_event_id() {
+        let canonicalizer = Canonicalizer::new(ProfileId::parse("northroot-canonical-v1").unwrap());
+        let value = json!({"b": "2", "a": 1});
+
+        let report = debug_report(&value, &canonicalizer).unwrap();
+        let lines: Vec<&str> = report.lines().collect();
+
+        let canonical = canonicalizer.canonicalize(&value).unwrap().bytes;
+        assert_eq!(canonical, br#"{"a":1,"b":"2"}"#);
+        assert_eq!(lines[0], format!("hex: {}", encode_hex(&canonical)));
+        assert_eq!(lines[0], "hex: 7b2261223a312c2262223a2232227d");
+        assert_eq!(lines[1], r#"utf8: {"a":1,"b":"2"}"#);
+        let event_id = compute_event_id(&value, &canonicalizer).unwrap();
+        assert_eq!(lines[2], format!("event_id: {}", event_id.b64));
+    }
+
+    #[test]
+    fn debug_report_shows_preimage_without_event_id() {
+        let canonicalizer = Canonicalizer::new(ProfileId::parse("northroot-canonical-v1").unwrap());
+        let value = json!({
+            "event_id": {"alg": "sha-256", "b64": "stale"},
+            "b": "2",
+            "a": 1
+        });
+
+        let report = debug_report(&value, &canonicalizer).unwrap();
+        let lines: Vec<&str> = report.lines().collect();
+
+        assert_eq!(lines[0], "hex: 7b2261223a312c2262223a2232227d");
+        assert_eq!(lines[1], r#"utf8: {"a":1,"b":"2"}"#);
+        let event_id = compute_event_id(&json!({"b": "2", "a": 1}), &canonicalizer).unwrap();
+        assert_eq!(lines[2], format!("event_id: {}", event_id.b64));
+    }
+}
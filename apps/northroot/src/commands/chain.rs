@@ -0,0 +1,223 @@
+//! Chain command implementation.
+
+use crate::path;
+use northroot_canonical::{Canonicalizer, ProfileId};
+use northroot_journal::{ChainVerifier, JournalReader, ReadMode};
+use serde_json::json;
+use std::path::Path;
+
+/// One event's link in the `prev_event_id` chain.
+#[derive(Debug, PartialEq, Eq)]
+struct ChainLink {
+    event_id: String,
+    prev_event_id: Option<String>,
+    linked: bool,
+}
+
+impl ChainLink {
+    /// A linked event without `prev_event_id` starts the chain.
+    fn is_genesis(&self) -> bool {
+        self.linked && self.prev_event_id.is_none()
+    }
+}
+
+pub fn run(journal: String, json_output: bool) -> Result<(), Box<dyn std::error::Error>> {
+    // Validate and normalize journal path
+    let journal_path = path::validate_journal_path(&journal, false)
+        .map_err(|e| format!("Invalid journal path: {}", e))?;
+
+    let links = walk_chain(&journal_path)?;
+    print!("{}", format_chain(&links, json_output)?);
+    Ok(())
+}
+
+/// Walks a journal in file order, checking each `prev_event_id` link.
+///
+/// A link is valid when it names the recomputed `event_id` of the preceding
+/// event, as with `verify --chain`; the first event must have no
+/// `prev_event_id`.
+fn walk_chain(journal_path: &Path) -> Result<Vec<ChainLink>, Box<dyn std::error::Error>> {
+    let profile = ProfileId::parse("northroot-canonical-v1")
+        .map_err(|e| format!("Invalid profile ID: {}", e))?;
+    let canonicalizer = Canonicalizer::new(profile);
+
+    let mut reader = JournalReader::open(journal_path, ReadMode::Strict).map_err(|e| {
+        let sanitized = path::sanitize_path_for_error(journal_path);
+        format!("Failed to open journal file: {}: {}", sanitized, e)
+    })?;
+
+    let mut chain = ChainVerifier::new(&canonicalizer);
+    let mut links = Vec::new();
+    while let Some(event) = reader.read_event()? {
+        let event_id = event
+            .get("event_id")
+            .and_then(|v| v.get("b64"))
+            .and_then(|v| v.as_str())
+            .unwrap_or("?")
+            .to_string();
+        let chain_break = chain
+            .check(&event)
+            .map_err(|e| format!("Event {}: {}", links.len(), e))?;
+        let prev_event_id = event
+            .get("prev_event_id")
+            .and_then(|v| v.get("b64"))
+            .and_then(|v| v.as_str())
+            .map(str::to_string);
+        links.push(ChainLink {
+            event_id,
+            prev_event_id,
+            linked: chain_break.is_none(),
+        });
+    }
+    Ok(links)
+}
+
+/// Renders the chain as a table with a summary line, or a JSON array.
+fn format_chain(
+    links: &[ChainLink],
+    json_output: bool,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let mut out = String::new();
+    if json_output {
+        let rows: Vec<_> = links
+            .iter()
+            .map(|link| {
+                json!({
+                    "event_id": link.event_id,
+                    "prev_event_id": link.prev_event_id,
+                    "linked": link.linked
+                })
+            })
+            .collect();
+        out.push_str(&serde_json::to_string_pretty(&rows)?);
+        out.push('\n');
+    } else {
+        out.push_str(&format!(
+            "{:<44} {:<44} LINK\n",
+            "EVENT_ID", "PREV_EVENT_ID"
+        ));
+        out.push_str(&format!("{}\n", "-".repeat(96)));
+        for link in links {
+            let status = if link.is_genesis() {
+                "genesis"
+            } else if link.linked {
+                "✓"
+            } else {
+                "✗ BREAK"
+            };
+            out.push_str(&format!(
+                "{:<44} {:<44} {}\n",
+                link.event_id,
+                link.prev_event_id.as_deref().unwrap_or("-"),
+                status
+            ));
+        }
+        let broken = links.iter().filter(|link| !link.linked).count();
+        out.push_str(&format!(
+            "\n{} events: {} linked, {} broken\n",
+            links.len(),
+            links.len() - broken,
+            broken
+        ));
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use northroot_canonical::compute_event_id;
+    use northroot_journal::{JournalWriter, WriteOptions};
+    use serde_json::Value;
+    use tempfile::TempDir;
+
+    fn sign(mut event: Value) -> Value {
+        let canonicalizer = Canonicalizer::new(ProfileId::parse("northroot-canonical-v1").unwrap());
+        let event_id = compute_event_id(&event, &canonicalizer).unwrap();
+        event["event_id"] = serde_json::to_value(event_id).unwrap();
+        event
+    }
+
+    /// Builds `count` events, each linking to the one before it.
+    fn linked_events(count: usize) -> Vec<Value> {
+        let mut events: Vec<Value> = Vec::new();
+        for sequence in 0..count {
+            let mut event = json!({
+                "event_type": "test",
+                "event_version": "1",
+                "sequence": sequence
+            });
+            if let Some(prev) = events.last() {
+                event["prev_event_id"] = prev["event_id"].clone();
+            }
+            events.push(sign(event));
+        }
+        events
+    }
+
+    fn write_journal(path: &Path, events: &[Value]) {
+        let mut writer = JournalWriter::open(path, WriteOptions::default()).unwrap();
+        for event in events {
+            writer.append_event(event).unwrap();
+        }
+        writer.finish().unwrap();
+    }
+
+    #[test]
+    fn walks_generated_chain_and_marks_genesis() {
+        let temp = TempDir::new().unwrap();
+        let journal = temp.path().join("events.nrj");
+        let events = linked_events(4);
+        write_journal(&journal, &events);
+
+        let links = walk_chain(&journal).unwrap();
+        assert_eq!(links.len(), 4);
+        assert!(links.iter().all(|link| link.linked));
+        assert!(links[0].is_genesis());
+        for (link, prev) in links[1..].iter().zip(&links) {
+            assert_eq!(link.prev_event_id.as_ref(), Some(&prev.event_id));
+        }
+
+        let table = format_chain(&links, false).unwrap();
+        assert!(table.contains("genesis"));
+        assert!(table.ends_with("4 events: 4 linked, 0 broken\n"));
+    }
+
+    #[test]
+    fn flags_broken_links() {
+        let temp = TempDir::new().unwrap();
+        let journal = temp.path().join("events.nrj");
+        let mut events = linked_events(3);
+        // Drop the middle event, so the last one links to a missing predecessor.
+        let missing = events.remove(1);
+        write_journal(&journal, &events);
+
+        let links = walk_chain(&journal).unwrap();
+        assert!(links[0].linked);
+        assert!(!links[1].linked);
+        assert_eq!(
+            links[1].prev_event_id.as_deref(),
+            missing["event_id"]["b64"].as_str()
+        );
+
+        let json: Value = serde_json::from_str(&format_chain(&links, true).unwrap()).unwrap();
+        assert_eq!(
+            json,
+            json!([
+                {
+                    "event_id": events[0]["event_id"]["b64"],
+                    "prev_event_id": null,
+                    "linked": true
+                },
+                {
+                    "event_id": events[1]["event_id"]["b64"],
+                    "prev_event_id": missing["event_id"]["b64"],
+                    "linked": false
+                }
+            ])
+        );
+        let table = format_chain(&links, false).unwrap();
+        assert!(table.contains("✗ BREAK"));
+        assert!(table.ends_with("2 events: 1 linked, 1 broken\n"));
+    }
+}
@@ -1,9 +1,10 @@
 //! Event ID command implementation.
 
-use northroot_canonical::{compute_event_id, parse_json_strict, Canonicalizer, ProfileId};
+use northroot_canonical::{compute_event_id, parse_json_strict, Canonicalizer, Digest, ProfileId};
+use serde_json::Value;
 use std::io::{self, Read};
 
-pub fn run(input: Option<String>) -> Result<(), Box<dyn std::error::Error>> {
+pub fn run(input: Option<String>, check: bool) -> Result<(), Box<dyn std::error::Error>> {
     let profile = ProfileId::parse("northroot-canonical-v1")
         .map_err(|e| format!("Invalid profile ID: {}", e))?;
     let canonicalizer = Canonicalizer::new(profile);
@@ -20,6 +21,18 @@ pub fn run(input: Option<String>) -> Result<(), Box<dyn std::error::Error>> {
 
     let value = parse_json_strict(&json_str).map_err(|e| format!("Invalid JSON: {}", e))?;
 
+    if check {
+        let (claimed, computed) = check_event_id(&value, &canonicalizer)?;
+        if claimed != computed {
+            eprintln!("event_id mismatch");
+            eprintln!("  claimed:  {}", claimed.b64);
+            eprintln!("  computed: {}", computed.b64);
+            std::process::exit(1);
+        }
+        println!("{}", computed.b64);
+        return Ok(());
+    }
+
     let event_id = compute_event_id(&value, &canonicalizer)
         .map_err(|e| format!("Event ID computation failed: {}", e))?;
 
@@ -27,9 +40,25 @@ pub fn run(input: Option<String>) -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Returns an event's embedded `event_id` and the one computed from its content.
+fn check_event_id(
+    value: &Value,
+    canonicalizer: &Canonicalizer,
+) -> Result<(Digest, Digest), Box<dyn std::error::Error>> {
+    let claimed: Digest = value
+        .get("event_id")
+        .cloned()
+        .and_then(|id| serde_json::from_value(id).ok())
+        .ok_or("Input has no valid event_id to check")?;
+    let computed = compute_event_id(value, canonicalizer)
+        .map_err(|e| format!("Event ID computation failed: {}", e))?;
+    Ok((claimed, computed))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::run;
+    use super::*;
+    use serde_json::json;
     use std::fs;
     use tempfile::TempDir;
 
@@ -39,9 +68,42 @@ mod tests {
         let input = temp.path().join("event.json");
         fs::write(&input, r#"{"a":1,"a":2}"#).unwrap();
 
-        let result = run(Some(input.to_str().unwrap().to_string()));
+        let result = run(Some(input.to_str().unwrap().to_string()), false);
 
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("duplicate key"));
     }
+
+    #[test]
+    fn check_compares_embedded_and_computed_event_id() {
+        let canonicalizer = Canonicalizer::new(ProfileId::parse("northroot-canonical-v1").unwrap());
+        let mut event = json!({"event_type": "test", "event_version": "1"});
+        let event_id = compute_event_id(&event, &canonicalizer).unwrap();
+        event["event_id"] = serde_json::to_value(&event_id).unwrap();
+
+        let (claimed, computed) = check_event_id(&event, &canonicalizer).unwrap();
+        assert_eq!(claimed, computed);
+        assert_eq!(computed, event_id);
+
+        event["event_type"] = json!("tampered");
+        let (claimed, computed) = check_event_id(&event, &canonicalizer).unwrap();
+        assert_eq!(claimed, event_id);
+        assert_ne!(claimed, computed);
+
+        event.as_object_mut().unwrap().remove("event_id");
+        assert!(check_event_id(&event, &canonicalizer).is_err());
+    }
+
+    #[test]
+    fn check_passes_for_matching_input_file() {
+        let temp = TempDir::new().unwrap();
+        let input = temp.path().join("event.json");
+        let canonicalizer = Canonicalizer::new(ProfileId::parse("northroot-canonical-v1").unwrap());
+        let mut event = json!({"event_type": "test", "event_version": "1"});
+        event["event_id"] =
+            serde_json::to_value(compute_event_id(&event, &canonicalizer).unwrap()).unwrap();
+        fs::write(&input, event.to_string()).unwrap();
+
+        assert!(run(Some(input.to_str().unwrap().to_string()), true).is_ok());
+    }
 }
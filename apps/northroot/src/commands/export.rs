@@ -0,0 +1,260 @@
+//! Export command implementation.
+
+use crate::output::encode_hex;
+use crate::path;
+use base64::Engine;
+use northroot_canonical::{
+    event_id_from_canonical_bytes, event_id_preimage, Canonicalizer, Digest, ProfileId,
+};
+use northroot_journal::{EventJson, JournalReader, ReadMode};
+use std::io::{self, Write};
+use std::path::Path;
+
+/// Columns of `--format csv`, in order.
+const CSV_COLUMNS: [&str; 7] = [
+    "event_id",
+    "event_type",
+    "occurred_at",
+    "principal_id",
+    "tool_name",
+    "outcome",
+    "meter_used",
+];
+
+pub fn run(
+    journal: String,
+    format: String,
+    encoding: String,
+) -> Result<(), Box<dyn std::error::Error>> {
+    // Validate and normalize journal path
+    let journal_path = path::validate_journal_path(&journal, false)
+        .map_err(|e| format!("Invalid journal path: {}", e))?;
+
+    if format != "preimage" {
+        let mut out = io::BufWriter::new(io::stdout().lock());
+        export_events(&journal_path, &format, &mut out)?;
+        out.flush()?;
+        return Ok(());
+    }
+
+    for line in export_journal(&journal_path, &encoding)? {
+        println!("{}", line);
+    }
+
+    Ok(())
+}
+
+/// Streams every event in a journal to `out`, one line per event.
+///
+/// `jsonl` writes each event as compact JSON, unchanged. `csv` writes a
+/// header row and then one row per event with the [`CSV_COLUMNS`] fields:
+/// `event_id` is its `b64` value, `meter_used` is JSON-encoded, and missing
+/// or non-string fields are left empty. Events are written as they are read,
+/// so an error partway through leaves the earlier lines written.
+fn export_events(
+    journal_path: &Path,
+    format: &str,
+    out: &mut impl Write,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut reader = JournalReader::open(journal_path, ReadMode::Strict).map_err(|e| {
+        let sanitized = path::sanitize_path_for_error(journal_path);
+        format!("Failed to open journal file: {}: {}", sanitized, e)
+    })?;
+
+    match format {
+        "jsonl" => {
+            while let Some(event) = reader.read_event()? {
+                serde_json::to_writer(&mut *out, &event)?;
+                out.write_all(b"\n")?;
+            }
+        }
+        "csv" => {
+            writeln!(out, "{}", CSV_COLUMNS.join(","))?;
+            while let Some(event) = reader.read_event()? {
+                let row: Vec<String> = csv_fields(&event).iter().map(|f| csv_escape(f)).collect();
+                writeln!(out, "{}", row.join(","))?;
+            }
+        }
+        other => return Err(format!("Unsupported format: {}", other).into()),
+    }
+    Ok(())
+}
+
+fn csv_fields(event: &EventJson) -> [String; 7] {
+    let field = |name: &str| event[name].as_str().unwrap_or_default().to_string();
+    [
+        event["event_id"]["b64"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        field("event_type"),
+        field("occurred_at"),
+        field("principal_id"),
+        field("tool_name"),
+        field("outcome"),
+        event
+            .get("meter_used")
+            .map_or(String::new(), |meter_used| meter_used.to_string()),
+    ]
+}
+
+/// Quotes a CSV field when it contains a delimiter, quote, or line break.
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Encodes the event ID preimage of every event in a journal, one per line.
+///
+/// Each preimage is re-hashed and compared with the event's claimed
+/// `event_id` before anything is returned, so a journal with a missing or
+/// mismatched ID produces an error rather than partial output.
+fn export_journal(
+    journal_path: &Path,
+    encoding: &str,
+) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let profile = ProfileId::parse("northroot-canonical-v1")
+        .map_err(|e| format!("Invalid profile ID: {}", e))?;
+    let canonicalizer = Canonicalizer::new(profile);
+
+    let mut reader = JournalReader::open(journal_path, ReadMode::Strict).map_err(|e| {
+        let sanitized = path::sanitize_path_for_error(journal_path);
+        format!("Failed to open journal file: {}: {}", sanitized, e)
+    })?;
+
+    let mut lines = Vec::new();
+    let mut index: u64 = 0;
+    while let Some(event) = reader.read_event()? {
+        let claimed: Digest = event
+            .get("event_id")
+            .cloned()
+            .and_then(|value| serde_json::from_value(value).ok())
+            .ok_or_else(|| format!("Event {} has no valid event_id", index))?;
+
+        let bytes = event_id_preimage(&event, &canonicalizer)
+            .map_err(|e| format!("Event {}: canonicalization failed: {}", index, e))?;
+        let computed = event_id_from_canonical_bytes(&bytes)?;
+        if computed != claimed {
+            return Err(format!(
+                "Event {}: event_id mismatch (claimed {}, computed {})",
+                index, claimed.b64, computed.b64
+            )
+            .into());
+        }
+
+        lines.push(match encoding {
+            "hex" => encode_hex(&bytes),
+            "b64" => base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(&bytes),
+            other => return Err(format!("Unsupported encoding: {}", other).into()),
+        });
+        index += 1;
+    }
+
+    Ok(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use northroot_canonical::compute_event_id;
+    use northroot_journal::{JournalWriter, WriteOptions};
+    use serde_json::{json, Value};
+    use tempfile::TempDir;
+
+    fn signed_event(name: &str) -> Value {
+        let canonicalizer = Canonicalizer::new(ProfileId::parse("northroot-canonical-v1").unwrap());
+        let mut event = json!({
+            "event_type": name,
+            "event_version": "1",
+            "occurred_at": "2024-01-01T00:00:00Z"
+        });
+        let event_id = compute_event_id(&event, &canonicalizer).unwrap();
+        event["event_id"] = serde_json::to_value(event_id).unwrap();
+        event
+    }
+
+    fn write_journal(path: &Path, events: &[Value]) {
+        let mut writer = JournalWriter::open(path, WriteOptions::default()).unwrap();
+        for event in events {
+            writer.append_event(event).unwrap();
+        }
+        writer.finish().unwrap();
+    }
+
+    #[test]
+    fn exported_bytes_rehash_to_event_ids() {
+        let temp = TempDir::new().unwrap();
+        let journal = temp.path().join("events.nrj");
+        let events = [signed_event("first"), signed_event("second")];
+        write_journal(&journal, &events);
+
+        let lines = export_journal(&journal, "b64").unwrap();
+
+        assert_eq!(lines.len(), 2);
+        for (line, event) in lines.iter().zip(&events) {
+            let bytes = base64::engine::general_purpose::URL_SAFE_NO_PAD
+                .decode(line)
+                .unwrap();
+            let event_id = event_id_from_canonical_bytes(&bytes).unwrap();
+            assert_eq!(event["event_id"]["b64"], event_id.b64);
+        }
+
+        let hex = export_journal(&journal, "hex").unwrap();
+        assert!(hex[0].starts_with(&encode_hex(br#"{"event_type":"first""#)));
+    }
+
+    #[test]
+    fn rejects_event_id_mismatch() {
+        let temp = TempDir::new().unwrap();
+        let journal = temp.path().join("events.nrj");
+        let mut tampered = signed_event("first");
+        tampered["event_type"] = json!("tampered");
+        write_journal(&journal, &[signed_event("ok"), tampered]);
+
+        let err = export_journal(&journal, "hex").unwrap_err();
+
+        assert!(err.to_string().contains("Event 1: event_id mismatch"));
+    }
+
+    #[test]
+    fn jsonl_and_csv_stream_every_event_in_order() {
+        let temp = TempDir::new().unwrap();
+        let journal = temp.path().join("events.nrj");
+        let mut execution = signed_event("execution");
+        execution["tool_name"] = json!("shell, \"bash\"");
+        execution["outcome"] = json!("success");
+        execution["meter_used"] = json!({"cpu_ms": {"t": "int", "v": "5"}});
+        let events = [signed_event("first"), execution, signed_event("third")];
+        write_journal(&journal, &events);
+
+        let mut jsonl = Vec::new();
+        export_events(&journal, "jsonl", &mut jsonl).unwrap();
+        let lines: Vec<Value> = String::from_utf8(jsonl)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines, events);
+
+        let mut csv = Vec::new();
+        export_events(&journal, "csv", &mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let rows: Vec<_> = csv.lines().collect();
+        assert_eq!(rows.len(), 4);
+        assert_eq!(
+            rows[0],
+            "event_id,event_type,occurred_at,principal_id,tool_name,outcome,meter_used"
+        );
+        assert_eq!(
+            rows[2],
+            format!(
+                "{},execution,2024-01-01T00:00:00Z,,\"shell, \"\"bash\"\"\",success,\
+                 \"{{\"\"cpu_ms\"\":{{\"\"t\"\":\"\"int\"\",\"\"v\"\":\"\"5\"\"}}}}\"",
+                events[1]["event_id"]["b64"].as_str().unwrap()
+            )
+        );
+    }
+}
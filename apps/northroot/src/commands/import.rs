@@ -0,0 +1,187 @@
+//! Import command implementation.
+
+use super::append::prepare_event;
+use crate::path;
+use northroot_canonical::{parse_json_strict, Canonicalizer, ProfileId};
+use northroot_journal::{JournalWriter, WriteOptions};
+use serde_json::Value;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+pub fn run(input: String, output: String, strict: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let input_path = Path::new(&input);
+    let output_path = path::validate_journal_path_for_create(&output)
+        .map_err(|e| format!("Invalid output path: {}", e))?;
+
+    let summary = import_events(input_path, &output_path, strict)?;
+    for (line, error) in &summary.skipped {
+        eprintln!("Warning: skipped line {}: {}", line, error);
+    }
+    println!(
+        "Imported {} events into {} ({} lines skipped)",
+        summary.imported,
+        path::sanitize_path_for_error(&output_path),
+        summary.skipped.len()
+    );
+    Ok(())
+}
+
+/// Counts reported by [`import_events`].
+#[derive(Debug, PartialEq, Eq)]
+struct ImportSummary {
+    imported: usize,
+    /// Line numbers (1-based) and errors of malformed lines.
+    skipped: Vec<(usize, String)>,
+}
+
+/// Appends one JSON event per line of `input` to `output`.
+///
+/// Blank lines are ignored. Each event gets its computed `event_id`, as with
+/// `append`; with `strict`, a claimed `event_id` must match it. A malformed
+/// line aborts the import in strict mode and is skipped otherwise.
+///
+/// Every line is prepared before `output` is opened, so an aborted import
+/// writes nothing.
+fn import_events(
+    input: &Path,
+    output: &Path,
+    strict: bool,
+) -> Result<ImportSummary, Box<dyn std::error::Error>> {
+    let profile = ProfileId::parse("northroot-canonical-v1")
+        .map_err(|e| format!("Invalid profile ID: {}", e))?;
+    let canonicalizer = Canonicalizer::new(profile);
+
+    let file = std::fs::File::open(input)
+        .map_err(|e| format!("Failed to read file {}: {}", input.display(), e))?;
+    let mut events = Vec::new();
+    let mut skipped = Vec::new();
+    for (index, line) in BufReader::new(file).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        match prepare_line(&line, strict, &canonicalizer) {
+            Ok(event) => events.push(event),
+            Err(e) if strict => return Err(format!("Line {}: {}", index + 1, e).into()),
+            Err(e) => skipped.push((index + 1, e.to_string())),
+        }
+    }
+
+    let mut writer = JournalWriter::open(output, WriteOptions::default()).map_err(|e| {
+        let sanitized = path::sanitize_path_for_error(output);
+        format!("Failed to open journal file: {}: {}", sanitized, e)
+    })?;
+    for event in &events {
+        writer.append_event(event)?;
+    }
+    writer.finish()?;
+
+    Ok(ImportSummary {
+        imported: events.len(),
+        skipped,
+    })
+}
+
+/// Parses one line of newline-delimited JSON and prepares it as an event.
+pub(super) fn prepare_line(
+    line: &str,
+    strict: bool,
+    canonicalizer: &Canonicalizer,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let event: Value = parse_json_strict(line).map_err(|e| format!("Invalid JSON: {}", e))?;
+    if !event.is_object() {
+        return Err("event must be a JSON object".into());
+    }
+    prepare_event(event, strict, canonicalizer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use northroot_canonical::compute_event_id;
+    use northroot_journal::{JournalReader, ReadMode};
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    fn read_journal(path: &Path) -> Vec<Value> {
+        let mut reader = JournalReader::open(path, ReadMode::Strict).unwrap();
+        let mut events = Vec::new();
+        while let Some(event) = reader.read_event().unwrap() {
+            events.push(event);
+        }
+        events
+    }
+
+    fn canonicalizer() -> Canonicalizer {
+        Canonicalizer::new(ProfileId::parse("northroot-canonical-v1").unwrap())
+    }
+
+    #[test]
+    fn imports_every_line_with_computed_event_ids() {
+        let temp = TempDir::new().unwrap();
+        let input = temp.path().join("events.jsonl");
+        let output = temp.path().join("events.nrj");
+        let events: Vec<Value> = (0..100)
+            .map(|i| json!({"event_type": "test", "event_version": "1", "sequence": i}))
+            .collect();
+        let mut lines: Vec<String> = events.iter().map(Value::to_string).collect();
+        lines.insert(50, String::new());
+        std::fs::write(&input, lines.join("\n")).unwrap();
+
+        let summary = import_events(&input, &output, true).unwrap();
+
+        assert_eq!(
+            summary,
+            ImportSummary {
+                imported: 100,
+                skipped: Vec::new()
+            }
+        );
+        let imported = read_journal(&output);
+        assert_eq!(imported.len(), 100);
+        let canonicalizer = canonicalizer();
+        for (event, source) in imported.iter().zip(&events) {
+            let event_id = compute_event_id(source, &canonicalizer).unwrap();
+            assert_eq!(event["event_id"], serde_json::to_value(&event_id).unwrap());
+            assert_eq!(event["sequence"], source["sequence"]);
+        }
+
+        // Re-importing the events as `export --format jsonl` writes them
+        // keeps every event_id.
+        let exported_path = temp.path().join("exported.jsonl");
+        let exported: Vec<String> = imported.iter().map(Value::to_string).collect();
+        std::fs::write(&exported_path, exported.join("\n")).unwrap();
+        let round_trip = temp.path().join("round-trip.nrj");
+        import_events(&exported_path, &round_trip, true).unwrap();
+        assert_eq!(read_journal(&round_trip), imported);
+    }
+
+    #[test]
+    fn malformed_lines_abort_in_strict_mode_and_are_skipped_otherwise() {
+        let temp = TempDir::new().unwrap();
+        let input = temp.path().join("events.jsonl");
+        let output = temp.path().join("events.nrj");
+        let mut mismatched = json!({"event_type": "claimed", "event_version": "1"});
+        mismatched["event_id"] = json!({"alg": "sha-256", "b64": "AAAA"});
+        std::fs::write(
+            &input,
+            format!(
+                "{}\n{{not json\n[1, 2]\n{}\n",
+                json!({"event_type": "ok", "event_version": "1"}),
+                mismatched
+            ),
+        )
+        .unwrap();
+
+        let err = import_events(&input, &output, true).unwrap_err();
+        assert!(err.to_string().starts_with("Line 2: Invalid JSON"));
+        assert!(!output.exists());
+
+        let summary = import_events(&input, &output, false).unwrap();
+        assert_eq!(summary.imported, 2);
+        let skipped: Vec<_> = summary.skipped.iter().map(|(line, _)| *line).collect();
+        assert_eq!(skipped, [2, 3]);
+        assert_eq!(summary.skipped[1].1, "event must be a JSON object");
+        assert_eq!(read_journal(&output).len(), 2);
+    }
+}
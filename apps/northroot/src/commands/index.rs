@@ -0,0 +1,23 @@
+//! Index command implementation.
+
+use crate::path;
+use northroot_journal::{build_index, JournalIndex};
+
+pub fn run(journal: String) -> Result<(), Box<dyn std::error::Error>> {
+    // Validate and normalize journal path
+    let journal_path = path::validate_journal_path(&journal, false)
+        .map_err(|e| format!("Invalid journal path: {}", e))?;
+
+    let index = build_index(&journal_path).map_err(|e| {
+        let sanitized = path::sanitize_path_for_error(&journal_path);
+        format!("Failed to index journal file: {}: {}", sanitized, e)
+    })?;
+    index.save(&journal_path)?;
+
+    println!(
+        "Indexed {} events to {}",
+        index.len(),
+        path::sanitize_path_for_error(&JournalIndex::sidecar_path(&journal_path))
+    );
+    Ok(())
+}
@@ -0,0 +1,252 @@
+//! Merge command implementation.
+
+use crate::path;
+use northroot_canonical::{Digest, Timestamp};
+use northroot_journal::{EventJson, JournalReader, JournalWriter, ReadMode, WriteOptions};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+pub fn run(
+    inputs: Vec<String>,
+    output: String,
+    force: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let input_paths = inputs
+        .iter()
+        .map(|input| {
+            path::validate_journal_path(input, false)
+                .map_err(|e| format!("Invalid journal path: {}", e))
+        })
+        .collect::<Result<Vec<PathBuf>, _>>()?;
+    let output_path = path::validate_journal_path_for_create(&output)
+        .map_err(|e| format!("Invalid output path: {}", e))?;
+
+    let summary = merge_journals(&input_paths, &output_path, force)?;
+    println!(
+        "Merged {} events from {} journals into {} ({} duplicates dropped)",
+        summary.written,
+        input_paths.len(),
+        path::sanitize_path_for_error(&output_path),
+        summary.duplicates
+    );
+    Ok(())
+}
+
+/// Counts reported by [`merge_journals`].
+#[derive(Debug, PartialEq, Eq)]
+struct MergeSummary {
+    written: usize,
+    duplicates: usize,
+}
+
+/// Merges journals into `output`, ordered by `occurred_at`.
+///
+/// Events are copied unchanged. Only the first occurrence of each `event_id`
+/// is kept, and events with equal timestamps keep their input order (earlier
+/// files first, then file order). Timestamps compare at millisecond
+/// precision.
+///
+/// Every input is read into memory before `output` is written, so memory use
+/// grows with the combined size of the inputs. Reading everything first also
+/// means `output` may be one of the inputs when `force` is set. The merged
+/// journal is written to a temporary file beside `output` and renamed over it
+/// once complete, so a failed merge leaves `output` untouched.
+fn merge_journals(
+    inputs: &[PathBuf],
+    output: &Path,
+    force: bool,
+) -> Result<MergeSummary, Box<dyn std::error::Error>> {
+    if output.exists() && !force {
+        return Err(format!(
+            "Output already exists: {} (use --force to overwrite)",
+            path::sanitize_path_for_error(output)
+        )
+        .into());
+    }
+
+    let mut seen_ids: HashSet<[u8; 32]> = HashSet::new();
+    let mut events: Vec<(i64, EventJson)> = Vec::new();
+    let mut duplicates = 0;
+    for input in inputs {
+        let sanitized = path::sanitize_path_for_error(input);
+        let mut reader = JournalReader::open(input, ReadMode::Strict)
+            .map_err(|e| format!("Failed to open journal file: {}: {}", sanitized, e))?;
+        let mut index: u64 = 0;
+        while let Some(event) = reader.read_event()? {
+            let event_id = event
+                .get("event_id")
+                .and_then(|value| serde_json::from_value::<Digest>(value.clone()).ok())
+                .and_then(|digest| digest.to_bytes())
+                .ok_or_else(|| format!("{}: event {} has no valid event_id", sanitized, index))?;
+            let occurred_at = event
+                .get("occurred_at")
+                .and_then(|value| value.as_str())
+                .and_then(|value| Timestamp::parse(value).ok())
+                .and_then(|timestamp| timestamp.unix_millis())
+                .ok_or_else(|| {
+                    format!("{}: event {} has no valid occurred_at", sanitized, index)
+                })?;
+            index += 1;
+
+            if seen_ids.insert(event_id) {
+                events.push((occurred_at, event));
+            } else {
+                duplicates += 1;
+            }
+        }
+    }
+
+    // Stable sort keeps input order for equal timestamps.
+    events.sort_by_key(|(occurred_at, _)| *occurred_at);
+
+    let dir = match output.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    let temp = tempfile::Builder::new()
+        .prefix(".merge-")
+        .suffix(".nrj")
+        .tempfile_in(dir)?;
+    let mut writer = JournalWriter::open(temp.path(), WriteOptions::default())?;
+    for (_, event) in &events {
+        writer.append_event(event)?;
+    }
+    writer.finish()?;
+    temp.as_file().sync_all()?;
+    if force {
+        temp.persist(output)?;
+    } else {
+        temp.persist_noclobber(output)?;
+    }
+
+    Ok(MergeSummary {
+        written: events.len(),
+        duplicates,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use northroot_canonical::{compute_event_id, verify_event_id, Canonicalizer, ProfileId};
+    use serde_json::{json, Value};
+    use tempfile::TempDir;
+
+    fn canonicalizer() -> Canonicalizer {
+        Canonicalizer::new(ProfileId::parse("northroot-canonical-v1").unwrap())
+    }
+
+    fn signed_event(name: &str, occurred_at: &str) -> Value {
+        let mut event = json!({
+            "event_type": name,
+            "event_version": "1",
+            "occurred_at": occurred_at
+        });
+        let event_id = compute_event_id(&event, &canonicalizer()).unwrap();
+        event["event_id"] = serde_json::to_value(event_id).unwrap();
+        event
+    }
+
+    fn write_journal(path: &Path, events: &[Value]) {
+        let mut writer = JournalWriter::open(path, WriteOptions::default()).unwrap();
+        for event in events {
+            writer.append_event(event).unwrap();
+        }
+        writer.finish().unwrap();
+    }
+
+    fn read_journal(path: &Path) -> Vec<Value> {
+        let mut reader = JournalReader::open(path, ReadMode::Strict).unwrap();
+        let mut events = Vec::new();
+        while let Some(event) = reader.read_event().unwrap() {
+            events.push(event);
+        }
+        events
+    }
+
+    #[test]
+    fn merges_in_occurred_at_order_without_duplicates() {
+        let temp = TempDir::new().unwrap();
+        let day1 = temp.path().join("day1.nrj");
+        let day2 = temp.path().join("day2.nrj");
+        let output = temp.path().join("merged.nrj");
+        let shared = signed_event("shared", "2024-01-01T00:00:02Z");
+        write_journal(
+            &day1,
+            &[
+                signed_event("a", "2024-01-01T00:00:01Z"),
+                shared.clone(),
+                signed_event("tie-first", "2024-01-01T00:00:03Z"),
+            ],
+        );
+        write_journal(
+            &day2,
+            &[
+                signed_event("early", "2024-01-01T00:00:00.5Z"),
+                shared,
+                signed_event("tie-second", "2024-01-01T00:00:03Z"),
+            ],
+        );
+
+        let summary = merge_journals(&[day1, day2], &output, false).unwrap();
+
+        assert_eq!(
+            summary,
+            MergeSummary {
+                written: 5,
+                duplicates: 1
+            }
+        );
+        let merged = read_journal(&output);
+        let types: Vec<_> = merged
+            .iter()
+            .map(|event| event["event_type"].as_str().unwrap())
+            .collect();
+        assert_eq!(types, ["early", "a", "shared", "tie-first", "tie-second"]);
+        let canonicalizer = canonicalizer();
+        for event in &merged {
+            let claimed: Digest = serde_json::from_value(event["event_id"].clone()).unwrap();
+            assert!(verify_event_id(event, &claimed, &canonicalizer).unwrap());
+        }
+    }
+
+    #[test]
+    fn refuses_to_overwrite_without_force() {
+        let temp = TempDir::new().unwrap();
+        let input = temp.path().join("in.nrj");
+        let output = temp.path().join("out.nrj");
+        write_journal(&input, &[signed_event("a", "2024-01-01T00:00:00Z")]);
+        write_journal(&output, &[]);
+
+        let err = merge_journals(std::slice::from_ref(&input), &output, false).unwrap_err();
+        assert!(err.to_string().contains("use --force"));
+        assert!(read_journal(&output).is_empty());
+
+        merge_journals(&[input], &output, true).unwrap();
+        assert_eq!(read_journal(&output).len(), 1);
+    }
+
+    #[test]
+    fn force_replaces_an_input_in_one_step() {
+        let temp = TempDir::new().unwrap();
+        let first = temp.path().join("first.nrj");
+        let second = temp.path().join("second.nrj");
+        write_journal(&first, &[signed_event("b", "2024-01-01T00:00:02Z")]);
+        write_journal(&second, &[signed_event("a", "2024-01-01T00:00:01Z")]);
+
+        merge_journals(&[first.clone(), second], &first, true).unwrap();
+        let types: Vec<_> = read_journal(&first)
+            .iter()
+            .map(|event| event["event_type"].as_str().unwrap().to_string())
+            .collect();
+        assert_eq!(types, ["a", "b"]);
+
+        // The temporary file was renamed into place, not left behind.
+        let mut names: Vec<_> = std::fs::read_dir(temp.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        names.sort();
+        assert_eq!(names, ["first.nrj", "second.nrj"]);
+    }
+}
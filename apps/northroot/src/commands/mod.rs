@@ -2,11 +2,18 @@
 
 pub mod append;
 pub mod canonicalize;
+pub mod chain;
 pub mod event_id;
+pub mod export;
+pub mod import;
+pub mod index;
 pub mod journal;
+pub mod merge;
 pub mod node;
 pub mod read;
 pub mod record;
+pub mod repair;
+pub mod stats;
 pub mod steward;
 pub mod verify;
 pub mod verify_bundle;
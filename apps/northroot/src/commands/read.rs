@@ -2,14 +2,26 @@
 
 use crate::output;
 use crate::path;
-use northroot_journal::{JournalReader, ReadMode};
+use northroot_canonical::{Digest, DigestAlg};
+use northroot_journal::{
+    load_index, EventJson, JournalError, JournalHeader, JournalReader, ReadMode,
+};
 use serde_json;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// How often `--follow` checks the journal for new frames.
+const FOLLOW_POLL_INTERVAL: Duration = Duration::from_millis(250);
 
 pub fn run(
     journal: String,
     json: bool,
     max_events: Option<u64>,
     max_size: Option<u64>,
+    event_id: Option<String>,
+    follow: bool,
+    offsets: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
     // Validate and normalize journal path
     let journal_path = path::validate_journal_path(&journal, false)
@@ -28,32 +40,366 @@ pub fn run(
         }
     }
 
-    let mut reader = JournalReader::open(&journal_path, ReadMode::Strict).map_err(|e| {
-        let sanitized = path::sanitize_path_for_error(&journal_path);
-        format!("Failed to open journal file: {}: {}", sanitized, e)
-    })?;
+    if let Some(event_id) = event_id {
+        Digest::new(DigestAlg::Sha256, event_id.as_str())
+            .map_err(|e| format!("Invalid event ID: {}", e))?;
+        let event = find_event(&journal_path, &event_id)?
+            .ok_or_else(|| format!("Event not found: {}", event_id))?;
+        if json {
+            println!("{}", serde_json::to_string(&event)?);
+        } else {
+            output::print_table_header();
+            println!("{}", output::format_table_row(&event));
+        }
+        return Ok(());
+    }
 
     // Output header if table format
     if !json {
         output::print_table_header();
     }
 
-    let mut event_count: u64 = 0;
-    while let Some(event) = reader.read_event()? {
-        // Check max_events limit
-        if let Some(max) = max_events {
-            if event_count >= max {
-                break;
+    let limit = max_events.map_or(usize::MAX, |max| usize::try_from(max).unwrap_or(usize::MAX));
+    if follow {
+        let mut follower = Follower::open(&journal_path)?;
+        let mut printed = 0;
+        while printed < limit {
+            for event in follower.poll()?.into_iter().take(limit - printed) {
+                print_event(&event, json)?;
+                printed += 1;
             }
+            std::io::stdout().flush()?;
+            std::thread::sleep(FOLLOW_POLL_INTERVAL);
         }
+        return Ok(());
+    }
 
-        if json {
-            println!("{}", serde_json::to_string(&event)?);
-        } else {
-            println!("{}", output::format_table_row(&event));
+    let mut reader = open_reader(&journal_path)?;
+    if offsets {
+        for _ in 0..limit {
+            let Some(event) = read_event_with_offset(&mut reader)? else {
+                break;
+            };
+            print_event(&event, json)?;
         }
-        event_count += 1;
+        return Ok(());
+    }
+    for event in reader.events().take(limit) {
+        print_event(&event?, json)?;
     }
 
     Ok(())
 }
+
+fn print_event(event: &EventJson, json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    if json {
+        println!("{}", serde_json::to_string(event)?);
+    } else {
+        println!("{}", output::format_table_row(event));
+    }
+    Ok(())
+}
+
+/// Reads the next event, adding its frame's `_offset` and `_length` in bytes.
+///
+/// The offset can be passed back to `JournalReader::read_at_offset` to decode
+/// the same frame without scanning.
+fn read_event_with_offset(
+    reader: &mut JournalReader,
+) -> Result<Option<EventJson>, Box<dyn std::error::Error>> {
+    let Some((mut event, offset, length)) = reader.read_event_with_offset()? else {
+        return Ok(None);
+    };
+    let object = event
+        .as_object_mut()
+        .ok_or_else(|| format!("Event at offset {} is not a JSON object", offset))?;
+    object.insert("_offset".to_string(), offset.into());
+    object.insert("_length".to_string(), length.into());
+    Ok(Some(event))
+}
+
+fn open_reader(journal_path: &Path) -> Result<JournalReader, Box<dyn std::error::Error>> {
+    JournalReader::open(journal_path, ReadMode::Strict).map_err(|e| {
+        let sanitized = path::sanitize_path_for_error(journal_path);
+        format!("Failed to open journal file: {}: {}", sanitized, e).into()
+    })
+}
+
+/// Tails a journal that another process is appending to.
+///
+/// The reader is permissive, so a trailing frame that is still being
+/// written is left for a later poll instead of failing. If the journal is
+/// truncated below the read position, or replaced by another file (on Unix),
+/// it is read again from the start.
+struct Follower {
+    path: PathBuf,
+    reader: Option<JournalReader>,
+    file_id: Option<(u64, u64)>,
+}
+
+impl Follower {
+    fn open(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut follower = Self {
+            path: path.to_path_buf(),
+            reader: None,
+            file_id: None,
+        };
+        follower.reopen()?;
+        Ok(follower)
+    }
+
+    /// Opens the journal from the start once its header has been written.
+    fn reopen(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        self.reader = None;
+        let metadata = match std::fs::metadata(&self.path) {
+            Ok(metadata) => metadata,
+            // Between a rotation's rename and the new file's creation.
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+        self.file_id = file_id(&metadata);
+        if metadata.len() >= JournalHeader::HEADER_SIZE as u64 {
+            self.reader = Some(JournalReader::open(&self.path, ReadMode::Permissive)?);
+        }
+        Ok(())
+    }
+
+    /// Returns the events completed since the last poll.
+    fn poll(&mut self) -> Result<Vec<EventJson>, Box<dyn std::error::Error>> {
+        let replaced = match std::fs::metadata(&self.path) {
+            Ok(metadata) => {
+                file_id(&metadata) != self.file_id
+                    || self
+                        .reader
+                        .as_ref()
+                        .is_some_and(|reader| metadata.len() < reader.position())
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => true,
+            Err(e) => return Err(e.into()),
+        };
+        if replaced || self.reader.is_none() {
+            self.reopen()?;
+        }
+
+        let mut events = Vec::new();
+        if let Some(reader) = self.reader.as_mut() {
+            while let Some(event) = reader.read_event()? {
+                events.push(event);
+            }
+        }
+        Ok(events)
+    }
+}
+
+#[cfg(unix)]
+fn file_id(metadata: &std::fs::Metadata) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    Some((metadata.dev(), metadata.ino()))
+}
+
+#[cfg(not(unix))]
+fn file_id(_metadata: &std::fs::Metadata) -> Option<(u64, u64)> {
+    None
+}
+
+/// Finds the first event with the given `event_id.b64`.
+///
+/// Uses the journal's sidecar index when present, readable, and not stale.
+/// The index is only a hint: if it is corrupt, has no entry for the ID, or its
+/// offset no longer holds that event, the journal is scanned instead.
+fn find_event(
+    journal_path: &Path,
+    event_id: &str,
+) -> Result<Option<EventJson>, Box<dyn std::error::Error>> {
+    let mut reader = open_reader(journal_path)?;
+    let matches = |event: &EventJson| event["event_id"]["b64"].as_str() == Some(event_id);
+
+    let index = match load_index(journal_path) {
+        Ok(Some(index)) if !index.is_stale(journal_path)? => Some(index),
+        Ok(_) | Err(JournalError::InvalidIndex(_)) => None,
+        Err(e) => return Err(e.into()),
+    };
+    if let Some(offset) = index.and_then(|index| index.get(event_id)) {
+        if let Ok(Some(event)) = reader.read_at_offset(offset) {
+            if matches(&event) {
+                return Ok(Some(event));
+            }
+        }
+    }
+
+    while let Some(event) = reader.read_event()? {
+        if matches(&event) {
+            return Ok(Some(event));
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use northroot_journal::{build_index, JournalIndex, JournalWriter, WriteOptions};
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    fn event(id: &str) -> EventJson {
+        json!({
+            "event_id": {"alg": "sha-256", "b64": id},
+            "event_type": "test",
+            "event_version": "1"
+        })
+    }
+
+    fn append(path: &Path, ids: &[&str]) {
+        let mut writer = JournalWriter::open(path, WriteOptions::default()).unwrap();
+        for id in ids {
+            writer.append_event(&event(id)).unwrap();
+        }
+        writer.finish().unwrap();
+    }
+
+    #[test]
+    fn find_event_uses_index_and_falls_back_to_scan() {
+        let temp = TempDir::new().unwrap();
+        let journal = temp.path().join("events.nrj");
+        append(&journal, &["a", "b", "c"]);
+
+        assert_eq!(find_event(&journal, "b").unwrap(), Some(event("b")));
+        assert_eq!(find_event(&journal, "z").unwrap(), None);
+
+        build_index(&journal).unwrap().save(&journal).unwrap();
+        assert_eq!(find_event(&journal, "c").unwrap(), Some(event("c")));
+
+        // Events appended after indexing are still found by scanning.
+        append(&journal, &["d"]);
+        assert_eq!(find_event(&journal, "d").unwrap(), Some(event("d")));
+
+        // An index entry pointing at the wrong frame falls back to scanning.
+        let mut index = build_index(&journal).unwrap();
+        let wrong = index.get("a").unwrap();
+        std::fs::write(
+            JournalIndex::sidecar_path(&journal),
+            serde_json::to_string(&index).unwrap().replace(
+                &format!("\"b\":{}", index.get("b").unwrap()),
+                &format!("\"b\":{}", wrong),
+            ),
+        )
+        .unwrap();
+        index = load_index(&journal).unwrap().unwrap();
+        assert_eq!(index.get("b"), Some(wrong));
+        assert_eq!(find_event(&journal, "b").unwrap(), Some(event("b")));
+
+        // A corrupt sidecar is ignored as well.
+        std::fs::write(JournalIndex::sidecar_path(&journal), "garbage").unwrap();
+        assert_eq!(find_event(&journal, "c").unwrap(), Some(event("c")));
+    }
+
+    #[test]
+    fn offsets_locate_each_frame() {
+        let temp = TempDir::new().unwrap();
+        let journal = temp.path().join("events.nrj");
+        append(&journal, &["a", "b"]);
+        let options = WriteOptions {
+            frame_crc: true,
+            ..WriteOptions::default()
+        };
+        let mut writer = JournalWriter::open(&journal, options).unwrap();
+        writer.append_event(&event("c")).unwrap();
+        writer.finish().unwrap();
+
+        let mut reader = open_reader(&journal).unwrap();
+        let mut seek = open_reader(&journal).unwrap();
+        let mut expected_offset = JournalHeader::HEADER_SIZE as u64;
+        for id in ["a", "b", "c"] {
+            let mut annotated = read_event_with_offset(&mut reader).unwrap().unwrap();
+            let object = annotated.as_object_mut().unwrap();
+            let offset = object.remove("_offset").unwrap().as_u64().unwrap();
+            let length = object.remove("_length").unwrap().as_u64().unwrap();
+            assert_eq!(annotated, event(id));
+            assert_eq!(offset, expected_offset);
+            assert_eq!(seek.read_at_offset(offset).unwrap(), Some(event(id)));
+            expected_offset += length;
+        }
+        assert!(read_event_with_offset(&mut reader).unwrap().is_none());
+        assert_eq!(expected_offset, std::fs::metadata(&journal).unwrap().len());
+    }
+
+    #[test]
+    fn follower_emits_frames_appended_after_it_starts() {
+        let temp = TempDir::new().unwrap();
+        let journal = temp.path().join("events.nrj");
+        append(&journal, &["a"]);
+
+        let mut follower = Follower::open(&journal).unwrap();
+        assert_eq!(follower.poll().unwrap(), [event("a")]);
+        assert!(follower.poll().unwrap().is_empty());
+
+        append(&journal, &["b", "c"]);
+        assert_eq!(follower.poll().unwrap(), [event("b"), event("c")]);
+
+        // A frame written in two parts is only returned once complete.
+        let source = temp.path().join("source.nrj");
+        append(&source, &["d"]);
+        let frame = std::fs::read(&source).unwrap()[JournalHeader::HEADER_SIZE..].to_vec();
+        let (head, tail) = frame.split_at(frame.len() / 2);
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&journal)
+            .unwrap();
+        file.write_all(head).unwrap();
+        assert!(follower.poll().unwrap().is_empty());
+        file.write_all(tail).unwrap();
+        assert_eq!(follower.poll().unwrap(), [event("d")]);
+
+        // A rotated journal is followed from its start.
+        std::fs::rename(&journal, temp.path().join("events.1.nrj")).unwrap();
+        assert!(follower.poll().unwrap().is_empty());
+        append(&journal, &["e"]);
+        assert_eq!(follower.poll().unwrap(), [event("e")]);
+
+        // So is one truncated in place.
+        std::fs::OpenOptions::new()
+            .write(true)
+            .open(&journal)
+            .unwrap()
+            .set_len(JournalHeader::HEADER_SIZE as u64)
+            .unwrap();
+        assert!(follower.poll().unwrap().is_empty());
+        append(&journal, &["f"]);
+        assert_eq!(follower.poll().unwrap(), [event("f")]);
+    }
+
+    #[test]
+    fn follower_observes_concurrent_appends_in_order() {
+        let temp = TempDir::new().unwrap();
+        let journal = temp.path().join("events.nrj");
+        append(&journal, &["0"]);
+        let ids: Vec<String> = (1..50).map(|i| i.to_string()).collect();
+
+        let writer = {
+            let journal = journal.clone();
+            let ids = ids.clone();
+            std::thread::spawn(move || {
+                for id in &ids {
+                    append(&journal, &[id.as_str()]);
+                }
+            })
+        };
+
+        let mut follower = Follower::open(&journal).unwrap();
+        let mut seen = Vec::new();
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+        while seen.len() < 50 && std::time::Instant::now() < deadline {
+            seen.extend(follower.poll().unwrap());
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        writer.join().unwrap();
+
+        let expected: Vec<EventJson> = std::iter::once("0")
+            .chain(ids.iter().map(String::as_str))
+            .map(event)
+            .collect();
+        assert_eq!(seen, expected);
+        assert!(follower.poll().unwrap().is_empty());
+    }
+}
@@ -122,6 +122,10 @@ fn import_jsonl(
             sync,
             create: true,
             append: true,
+            write_footer: false,
+            frame_crc: false,
+            index: false,
+            compress: None,
         },
     )?;
 
@@ -0,0 +1,170 @@
+//! Repair command implementation.
+
+use crate::path;
+use northroot_journal::{FrameReader, JournalError, ReadMode};
+use std::fs::OpenOptions;
+use std::io::{self, Read};
+use std::path::Path;
+
+pub fn run(
+    journal: String,
+    output: Option<String>,
+    force: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let journal_path = path::validate_journal_path(&journal, false)
+        .map_err(|e| format!("Invalid journal path: {}", e))?;
+    let output_path = match output {
+        Some(output) => Some(
+            path::validate_journal_path_for_create(&output)
+                .map_err(|e| format!("Invalid output path: {}", e))?,
+        ),
+        None if force => None,
+        None => return Err("Specify --output, or --force to repair in place".into()),
+    };
+
+    let summary = repair_journal(&journal_path, output_path.as_deref())?;
+    println!(
+        "Kept {} frames ({} bytes); discarded {} bytes",
+        summary.frames, summary.kept_bytes, summary.discarded_bytes
+    );
+    Ok(())
+}
+
+/// What [`repair_journal`] kept and discarded.
+#[derive(Debug, PartialEq, Eq)]
+struct RepairSummary {
+    frames: u64,
+    kept_bytes: u64,
+    discarded_bytes: u64,
+}
+
+/// Drops everything after the last intact frame of a journal.
+///
+/// Frames are read permissively, so the intact prefix ends at the first
+/// frame that is truncated, fails its CRC, or has an invalid header. That
+/// prefix, header included, is kept byte for byte: it is copied to `output`
+/// (which must not exist), or the journal is truncated to it in place when
+/// `output` is `None`.
+fn repair_journal(
+    journal_path: &Path,
+    output: Option<&Path>,
+) -> Result<RepairSummary, Box<dyn std::error::Error>> {
+    let sanitized = path::sanitize_path_for_error(journal_path);
+    let mut frames = FrameReader::open(journal_path, ReadMode::Permissive)
+        .map_err(|e| format!("Failed to open journal file: {}: {}", sanitized, e))?;
+    let mut count = 0;
+    loop {
+        match frames.next_frame() {
+            Ok(Some(_)) => count += 1,
+            Ok(None) | Err(JournalError::InvalidFrame { .. }) => break,
+            Err(e) => return Err(e.into()),
+        }
+    }
+    let kept_bytes = frames.position();
+    let len = std::fs::metadata(journal_path)?.len();
+
+    match output {
+        Some(output) => {
+            let mut file = OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(output)
+                .map_err(|e| {
+                    format!(
+                        "Failed to create output file: {}: {}",
+                        path::sanitize_path_for_error(output),
+                        e
+                    )
+                })?;
+            let mut prefix = std::fs::File::open(journal_path)?.take(kept_bytes);
+            io::copy(&mut prefix, &mut file)?;
+            file.sync_all()?;
+        }
+        None => {
+            let file = OpenOptions::new().write(true).open(journal_path)?;
+            file.set_len(kept_bytes)?;
+            file.sync_all()?;
+        }
+    }
+
+    Ok(RepairSummary {
+        frames: count,
+        kept_bytes,
+        discarded_bytes: len - kept_bytes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use northroot_journal::{JournalReader, JournalWriter, WriteOptions};
+    use serde_json::{json, Value};
+    use tempfile::TempDir;
+
+    fn event(name: &str) -> Value {
+        json!({
+            "event_id": {"alg": "sha-256", "b64": name},
+            "event_type": "test",
+            "event_version": "1"
+        })
+    }
+
+    fn read_strict(path: &Path) -> Result<Vec<Value>, JournalError> {
+        let mut reader = JournalReader::open(path, ReadMode::Strict)?;
+        let mut events = Vec::new();
+        while let Some(event) = reader.read_event()? {
+            events.push(event);
+        }
+        Ok(events)
+    }
+
+    #[test]
+    fn drops_partial_trailing_frame() {
+        let temp = TempDir::new().unwrap();
+        let journal = temp.path().join("events.nrj");
+        let options = WriteOptions {
+            frame_crc: true,
+            ..WriteOptions::default()
+        };
+        let mut writer = JournalWriter::open(&journal, options).unwrap();
+        writer.append_event(&event("first")).unwrap();
+        writer.finish().unwrap();
+        let one_event = std::fs::read(&journal).unwrap();
+        let mut writer = JournalWriter::open(&journal, WriteOptions::default()).unwrap();
+        writer.append_event(&event("second")).unwrap();
+        writer.finish().unwrap();
+
+        // Cut the second frame in half.
+        let len = std::fs::metadata(&journal).unwrap().len();
+        let cut = len - (len - one_event.len() as u64) / 2;
+        OpenOptions::new()
+            .write(true)
+            .open(&journal)
+            .unwrap()
+            .set_len(cut)
+            .unwrap();
+        assert!(matches!(
+            read_strict(&journal),
+            Err(JournalError::TruncatedFrame { .. })
+        ));
+
+        let output = temp.path().join("repaired.nrj");
+        let summary = repair_journal(&journal, Some(&output)).unwrap();
+        assert_eq!(
+            summary,
+            RepairSummary {
+                frames: 1,
+                kept_bytes: one_event.len() as u64,
+                discarded_bytes: cut - one_event.len() as u64,
+            }
+        );
+        assert_eq!(std::fs::read(&output).unwrap(), one_event);
+        assert_eq!(read_strict(&output).unwrap(), [event("first")]);
+        assert!(repair_journal(&journal, Some(&output)).is_err());
+
+        // In place, the journal itself is truncated to the intact prefix.
+        repair_journal(&journal, None).unwrap();
+        assert_eq!(std::fs::read(&journal).unwrap(), one_event);
+        assert_eq!(repair_journal(&journal, None).unwrap().discarded_bytes, 0);
+    }
+}
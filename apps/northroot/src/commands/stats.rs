@@ -0,0 +1,342 @@
+//! Stats command implementation.
+
+use crate::path;
+use northroot_canonical::{Quantity, Timestamp};
+use northroot_journal::{EventJson, JournalReader, ReadMode, TypeCounts};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+/// Summary counts over the events in a journal.
+///
+/// Executions may carry `meter_used`, an object mapping each unit to a
+/// [`Quantity`]; totals are summed exactly per unit. A unit whose values
+/// cannot be summed (malformed, mixed kinds, or overflowing) totals to
+/// `None`.
+#[derive(Debug, Default)]
+struct Stats {
+    events: u64,
+    event_types: BTreeMap<String, u64>,
+    outcomes: BTreeMap<String, u64>,
+    meter_used: BTreeMap<String, Option<Quantity>>,
+    principals: BTreeSet<String>,
+    earliest: Option<(i64, String)>,
+    latest: Option<(i64, String)>,
+}
+
+impl Stats {
+    fn record(&mut self, event: &EventJson) {
+        self.events += 1;
+
+        let event_type = field(event, "event_type").unwrap_or("?");
+        *self.event_types.entry(event_type.to_string()).or_default() += 1;
+        if event_type == "execution" {
+            let outcome = field(event, "outcome").unwrap_or("?");
+            *self.outcomes.entry(outcome.to_string()).or_default() += 1;
+            self.record_meter(event);
+        }
+
+        if let Some(principal) = field(event, "principal_id") {
+            self.principals.insert(principal.to_string());
+        }
+
+        let occurred_at = field(event, "occurred_at").and_then(|occurred_at| {
+            let millis = Timestamp::parse(occurred_at).ok()?.unix_millis()?;
+            Some((millis, occurred_at.to_string()))
+        });
+        if let Some(occurred_at) = occurred_at {
+            if self
+                .earliest
+                .as_ref()
+                .is_none_or(|min| occurred_at.0 < min.0)
+            {
+                self.earliest = Some(occurred_at.clone());
+            }
+            if self.latest.as_ref().is_none_or(|max| occurred_at.0 > max.0) {
+                self.latest = Some(occurred_at);
+            }
+        }
+    }
+
+    fn record_meter(&mut self, event: &EventJson) {
+        let Some(meter_used) = event.get("meter_used").and_then(Value::as_object) else {
+            return;
+        };
+        for (unit, amount) in meter_used {
+            // Adding zero validates a unit's first value the same way later
+            // values are validated, so a lone malformed value totals to None.
+            let amount = serde_json::from_value::<Quantity>(amount.clone())
+                .ok()
+                .and_then(|amount| amount.checked_add(&Quantity::Int { v: "0".into() }).ok());
+            let total = match self.meter_used.remove(unit) {
+                None => amount,
+                Some(total) => total
+                    .zip(amount)
+                    .and_then(|(total, amount)| total.checked_add(&amount).ok()),
+            };
+            self.meter_used.insert(unit.clone(), total);
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "events": self.events,
+            "event_types": self.event_types,
+            "execution_outcomes": self.outcomes,
+            "meter_used": self.meter_used,
+            "principals": self.principals.len(),
+            "occurred_at": {
+                "min": self.earliest.as_ref().map(|(_, at)| at),
+                "max": self.latest.as_ref().map(|(_, at)| at)
+            }
+        })
+    }
+
+    fn to_table(&self) -> String {
+        let mut out = format!(
+            "{:<24} {}\n{:<24} {}\n{:<24} {}\n{:<24} {}\n",
+            "EVENTS",
+            self.events,
+            "PRINCIPALS",
+            self.principals.len(),
+            "OCCURRED_AT_MIN",
+            self.earliest.as_ref().map_or("-", |(_, at)| at.as_str()),
+            "OCCURRED_AT_MAX",
+            self.latest.as_ref().map_or("-", |(_, at)| at.as_str()),
+        );
+        out.push_str(&format!("\n{:<24} COUNT\n", "EVENT_TYPE"));
+        for (event_type, count) in &self.event_types {
+            out.push_str(&format!("{:<24} {}\n", event_type, count));
+        }
+        if !self.outcomes.is_empty() {
+            out.push_str(&format!("\n{:<24} COUNT\n", "EXECUTION_OUTCOME"));
+            for (outcome, count) in &self.outcomes {
+                out.push_str(&format!("{:<24} {}\n", outcome, count));
+            }
+        }
+        if !self.meter_used.is_empty() {
+            out.push_str(&format!("\n{:<24} TOTAL\n", "METER_UNIT"));
+            for (unit, total) in &self.meter_used {
+                let total = total
+                    .as_ref()
+                    .map_or("?".to_string(), Quantity::to_display_string);
+                out.push_str(&format!("{:<24} {}\n", unit, total));
+            }
+        }
+        out
+    }
+}
+
+/// Event counts by type from [`JournalReader::scan_types`], plus file size.
+#[derive(Debug)]
+struct TypeStats {
+    counts: TypeCounts,
+    bytes: u64,
+}
+
+impl TypeStats {
+    /// Counts by type, with untyped events under `?` as in [`Stats`].
+    fn event_types(&self) -> BTreeMap<&str, u64> {
+        let mut event_types: BTreeMap<&str, u64> = self
+            .counts
+            .by_type
+            .iter()
+            .map(|(event_type, count)| (event_type.as_str(), *count))
+            .collect();
+        if self.counts.untyped > 0 {
+            *event_types.entry("?").or_default() += self.counts.untyped;
+        }
+        event_types
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "events": self.counts.events,
+            "bytes": self.bytes,
+            "event_types": self.event_types()
+        })
+    }
+
+    fn to_table(&self) -> String {
+        let mut out = format!(
+            "{:<24} {}\n{:<24} {}\n",
+            "EVENTS", self.counts.events, "BYTES", self.bytes
+        );
+        out.push_str(&format!("\n{:<24} COUNT\n", "EVENT_TYPE"));
+        for (event_type, count) in self.event_types() {
+            out.push_str(&format!("{:<24} {}\n", event_type, count));
+        }
+        out
+    }
+}
+
+pub fn run(
+    journal: String,
+    json_output: bool,
+    principal: Option<String>,
+    types_only: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    // Validate and normalize journal path
+    let journal_path = path::validate_journal_path(&journal, false)
+        .map_err(|e| format!("Invalid journal path: {}", e))?;
+
+    if types_only {
+        let stats = collect_type_stats(&journal_path)?;
+        if json_output {
+            println!("{}", serde_json::to_string_pretty(&stats.to_json())?);
+        } else {
+            print!("{}", stats.to_table());
+        }
+        return Ok(());
+    }
+
+    let stats = collect_stats(&journal_path, principal.as_deref())?;
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&stats.to_json())?);
+    } else {
+        print!("{}", stats.to_table());
+    }
+
+    Ok(())
+}
+
+/// Streams a journal once, summarizing events from `principal` (or all events).
+fn collect_stats(
+    journal_path: &Path,
+    principal: Option<&str>,
+) -> Result<Stats, Box<dyn std::error::Error>> {
+    let mut reader = JournalReader::open(journal_path, ReadMode::Strict).map_err(|e| {
+        let sanitized = path::sanitize_path_for_error(journal_path);
+        format!("Failed to open journal file: {}: {}", sanitized, e)
+    })?;
+
+    let mut stats = Stats::default();
+    while let Some(event) = reader.read_event()? {
+        if principal.is_some_and(|principal| field(&event, "principal_id") != Some(principal)) {
+            continue;
+        }
+        stats.record(&event);
+    }
+    Ok(stats)
+}
+
+/// Counts events by type without building full event values.
+fn collect_type_stats(journal_path: &Path) -> Result<TypeStats, Box<dyn std::error::Error>> {
+    let mut reader = JournalReader::open(journal_path, ReadMode::Strict).map_err(|e| {
+        let sanitized = path::sanitize_path_for_error(journal_path);
+        format!("Failed to open journal file: {}: {}", sanitized, e)
+    })?;
+    Ok(TypeStats {
+        counts: reader.scan_types()?,
+        bytes: std::fs::metadata(journal_path)?.len(),
+    })
+}
+
+fn field<'a>(event: &'a EventJson, name: &str) -> Option<&'a str> {
+    event.get(name).and_then(|value| value.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use northroot_journal::{JournalWriter, WriteOptions};
+    use tempfile::TempDir;
+
+    fn event(event_type: &str, principal: &str, occurred_at: &str, outcome: Option<&str>) -> Value {
+        let mut event = json!({
+            "event_type": event_type,
+            "event_version": "1",
+            "principal_id": principal,
+            "occurred_at": occurred_at
+        });
+        if let Some(outcome) = outcome {
+            event["outcome"] = json!(outcome);
+        }
+        event
+    }
+
+    #[test]
+    fn counts_match_generated_journal() {
+        let temp = TempDir::new().unwrap();
+        let journal = temp.path().join("events.nrj");
+        let mut writer = JournalWriter::open(&journal, WriteOptions::default()).unwrap();
+        let mut events = [
+            event("authorization", "service:a", "2024-01-01T00:00:01Z", None),
+            event(
+                "execution",
+                "service:a",
+                "2024-01-01T00:00:00.5Z",
+                Some("success"),
+            ),
+            event(
+                "execution",
+                "service:b",
+                "2024-01-02T00:00:00Z",
+                Some("failure"),
+            ),
+            event(
+                "execution",
+                "service:a",
+                "2024-01-01T12:00:00Z",
+                Some("success"),
+            ),
+            event("checkpoint", "service:c", "not a time", None),
+        ];
+        events[1]["meter_used"] = json!({
+            "cpu_ms": {"t": "int", "v": "5"},
+            "usd": {"t": "dec", "m": "125", "s": 2},
+            "gpu": {"t": "int", "v": "1"}
+        });
+        events[2]["meter_used"] = json!({
+            "cpu_ms": {"t": "int", "v": "7"},
+            "bad": {"t": "dec", "m": "abc", "s": 1},
+            "huge": {"t": "dec", "m": "1", "s": 20_000_000}
+        });
+        events[3]["meter_used"] = json!({
+            "usd": {"t": "dec", "m": "5", "s": 1},
+            "gpu": {"t": "f64", "bits": "3ff0000000000000"}
+        });
+        for event in &events {
+            writer.append_event(event).unwrap();
+        }
+        writer.finish().unwrap();
+
+        let stats = collect_stats(&journal, None).unwrap().to_json();
+        assert_eq!(
+            stats,
+            json!({
+                "events": 5,
+                "event_types": {"authorization": 1, "checkpoint": 1, "execution": 3},
+                "execution_outcomes": {"failure": 1, "success": 2},
+                "meter_used": {
+                    "bad": null,
+                    "cpu_ms": {"t": "int", "v": "12"},
+                    "gpu": null,
+                    "huge": null,
+                    "usd": {"t": "dec", "m": "175", "s": 2}
+                },
+                "principals": 3,
+                "occurred_at": {
+                    "min": "2024-01-01T00:00:00.5Z",
+                    "max": "2024-01-02T00:00:00Z"
+                }
+            })
+        );
+
+        let types = collect_type_stats(&journal).unwrap();
+        assert_eq!(types.bytes, std::fs::metadata(&journal).unwrap().len());
+        assert_eq!(
+            types.to_json(),
+            json!({
+                "events": 5,
+                "bytes": types.bytes,
+                "event_types": stats["event_types"]
+            })
+        );
+
+        let scoped = collect_stats(&journal, Some("service:a")).unwrap();
+        assert_eq!(scoped.events, 3);
+        assert_eq!(scoped.outcomes.get("success"), Some(&2));
+        assert_eq!(scoped.latest.unwrap().1, "2024-01-01T12:00:00Z");
+    }
+}
@@ -1,23 +1,126 @@
 //! Verify command implementation.
 
 use crate::path;
-use northroot_canonical::{Canonicalizer, ProfileId};
-use northroot_journal::{JournalReader, ReadMode, verify_event_id};
+use northroot_canonical::{Canonicalizer, Digest, ProfileId};
+use northroot_journal::{
+    verify_event_id, ChainVerifier, EventJson, JournalError, JournalReader, OrderViolation,
+    ReadMode, TemporalOrderChecker,
+};
+use rayon::prelude::*;
 use serde_json::json;
+use std::collections::HashSet;
+use std::path::Path;
+
+/// Options controlling which checks `verify` runs and how results are reported.
+#[derive(Debug, Clone, Default)]
+pub struct VerifyOptions {
+    /// Stop after reading N events.
+    pub max_events: Option<u64>,
+    /// Reject journals larger than this many bytes.
+    pub max_size: Option<u64>,
+    /// Check that each event's `prev_event_id` names its predecessor.
+    pub chain: bool,
+    /// Abort on the first event_id mismatch instead of reporting it.
+    pub reject_tampered: bool,
+    /// Fail unless the journal ends with a footer covering every event.
+    pub require_complete: bool,
+    /// Check that `occurred_at` is non-decreasing, tolerating this much skew.
+    pub order_tolerance_ms: Option<u64>,
+    /// Warn on events whose `canonical_profile_id` differs from the first event's.
+    pub profile_check: bool,
+    /// Recompute event IDs on this many threads; 0 or 1 verifies sequentially.
+    pub jobs: usize,
+    /// List only invalid events, followed by a summary.
+    pub failures_only: bool,
+    /// Print only the valid/invalid counts.
+    pub count_only: bool,
+}
+
+/// Events read per chunk when event IDs are recomputed in parallel.
+const PARALLEL_CHUNK_SIZE: usize = 256;
+
+/// Verification outcome for a single journal event.
+///
+/// `reason` and `warning_reason` are machine-stable codes (such as
+/// `event_id_mismatch`) for `error` and `warning`; the messages are for
+/// people and may change.
+#[derive(Debug, PartialEq, Eq)]
+struct EventResult {
+    event_id: String,
+    valid: bool,
+    reason: Option<&'static str>,
+    error: Option<String>,
+    warning_reason: Option<&'static str>,
+    warning: Option<String>,
+}
+
+impl EventResult {
+    fn valid(event_id: String) -> Self {
+        Self {
+            event_id,
+            valid: true,
+            reason: None,
+            error: None,
+            warning_reason: None,
+            warning: None,
+        }
+    }
+
+    /// Marks the event invalid, replacing any earlier failure.
+    fn fail(&mut self, reason: &'static str, error: String) {
+        self.valid = false;
+        self.reason = Some(reason);
+        self.error = Some(error);
+    }
+
+    fn warn(&mut self, reason: &'static str, warning: String) {
+        self.warning_reason = Some(reason);
+        self.warning = Some(warning);
+    }
+
+    /// Turns a warning into a failure, as strict mode requires.
+    fn promote_warning(&mut self) {
+        if self.valid {
+            if let (Some(reason), Some(warning)) = (self.warning_reason.take(), self.warning.take())
+            {
+                self.fail(reason, warning);
+            }
+        }
+    }
+}
+
+/// Valid and invalid event counts for a verification run.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct VerifyCounts {
+    total: usize,
+    invalid: usize,
+}
+
+impl VerifyCounts {
+    fn record(&mut self, result: &EventResult) {
+        self.total += 1;
+        if !result.valid {
+            self.invalid += 1;
+        }
+    }
+
+    fn valid(&self) -> usize {
+        self.total - self.invalid
+    }
+}
 
 pub fn run(
     journal: String,
     strict: bool,
     json_output: bool,
-    max_events: Option<u64>,
-    max_size: Option<u64>,
+    options: VerifyOptions,
 ) -> Result<(), Box<dyn std::error::Error>> {
     // Validate and normalize journal path
     let journal_path = path::validate_journal_path(&journal, false)
         .map_err(|e| format!("Invalid journal path: {}", e))?;
 
     // Check journal size if limit is set
-    if let Some(max_bytes) = max_size {
+    if let Some(max_bytes) = options.max_size {
         let metadata = std::fs::metadata(&journal_path)?;
         if metadata.len() > max_bytes {
             return Err(format!(
@@ -29,28 +132,185 @@ pub fn run(
         }
     }
 
+    if options.require_complete && options.max_events.is_some() {
+        return Err("--require-complete cannot be combined with --max-events".into());
+    }
+
+    if options.count_only {
+        // Tally as events are verified instead of keeping every result.
+        let mut counts = VerifyCounts::default();
+        verify_journal_with(&journal_path, &options, |mut result| {
+            if strict {
+                result.promote_warning();
+            }
+            counts.record(&result);
+        })?;
+        print!("{}", format_counts(&counts, json_output)?);
+        if strict && counts.invalid > 0 {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    let mut results = verify_journal(&journal_path, &options)?;
+    if strict {
+        // Strict mode treats warnings as failures.
+        results.iter_mut().for_each(EventResult::promote_warning);
+    }
+    let all_ok = results.iter().all(|result| result.valid);
+
+    print!(
+        "{}",
+        format_report(&results, json_output, options.failures_only)?
+    );
+
+    if strict && !all_ok {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+/// Verifies every event in a journal, in file order.
+///
+/// Event IDs are content-derived, so a second occurrence of the same
+/// `event_id` is flagged as invalid even when its identity recomputes: it is
+/// either a replayed event or a hash collision. With `options.chain`, an
+/// event whose `prev_event_id` does not name its predecessor is invalid.
+///
+/// With `options.reject_tampered`, the first event_id mismatch aborts
+/// verification with an error naming the event and its frame offset.
+///
+/// With `options.require_complete`, a journal that has footers but does not
+/// end with one is an error. Journals without any footer predate the frame,
+/// so the check is skipped with a warning.
+///
+/// With `options.order_tolerance_ms`, an event whose `occurred_at` is missing
+/// or precedes the previous event's by more than the tolerance is invalid.
+///
+/// With `options.profile_check`, an event whose `canonical_profile_id`
+/// differs from the first event's gets a warning; each event still verifies
+/// under the fixed v1 profile.
+///
+/// With `options.jobs` above 1, events are read in chunks whose event IDs
+/// are recomputed on a thread pool of that size. The other checks depend on
+/// journal order and run sequentially, so results match a sequential run and
+/// stay in file order.
+fn verify_journal(
+    journal_path: &Path,
+    options: &VerifyOptions,
+) -> Result<Vec<EventResult>, Box<dyn std::error::Error>> {
+    let mut results = Vec::new();
+    verify_journal_with(journal_path, options, |result| results.push(result))?;
+    Ok(results)
+}
+
+/// Like [`verify_journal`], passing each result to `on_result` as it is produced.
+fn verify_journal_with(
+    journal_path: &Path,
+    options: &VerifyOptions,
+    mut on_result: impl FnMut(EventResult),
+) -> Result<(), Box<dyn std::error::Error>> {
     let profile = ProfileId::parse("northroot-canonical-v1")
         .map_err(|e| format!("Invalid profile ID: {}", e))?;
     let canonicalizer = Canonicalizer::new(profile);
 
-    let mut reader = JournalReader::open(&journal_path, ReadMode::Strict).map_err(|e| {
-        let sanitized = path::sanitize_path_for_error(&journal_path);
+    let mut reader = JournalReader::open(journal_path, ReadMode::Strict).map_err(|e| {
+        let sanitized = path::sanitize_path_for_error(journal_path);
         format!("Failed to open journal file: {}: {}", sanitized, e)
     })?;
 
-    let mut all_ok = true;
-    let mut results = Vec::new();
+    let mut checks = OrderedChecks {
+        options,
+        seen_ids: HashSet::new(),
+        chain: options.chain.then(|| ChainVerifier::new(&canonicalizer)),
+        order: options.order_tolerance_ms.map(TemporalOrderChecker::new),
+        journal_profile: None,
+    };
+    let pool = match options.jobs {
+        0 | 1 => None,
+        jobs => Some(
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(jobs)
+                .build()
+                .map_err(|e| format!("Failed to start {} verification threads: {}", jobs, e))?,
+        ),
+    };
+    let chunk_size = if pool.is_some() {
+        PARALLEL_CHUNK_SIZE
+    } else {
+        1
+    };
+    // Events of the current chunk, each with the frame offset it was read from.
+    let mut chunk: Vec<(u64, EventJson)> = Vec::with_capacity(chunk_size);
     let mut event_count: u64 = 0;
 
-    while let Some(event) = reader.read_event()? {
-        // Check max_events limit
-        if let Some(max) = max_events {
-            if event_count >= max {
+    loop {
+        chunk.clear();
+        while chunk.len() < chunk_size && options.max_events.is_none_or(|max| event_count < max) {
+            let offset = reader.position();
+            let Some(event) = reader.read_event()? else {
                 break;
+            };
+            chunk.push((offset, event));
+            event_count += 1;
+        }
+        if chunk.is_empty() {
+            break;
+        }
+
+        let id_checks: Vec<_> = match &pool {
+            Some(pool) => pool.install(|| {
+                chunk
+                    .par_iter()
+                    .map(|(_, event)| verify_event_id(event, &canonicalizer))
+                    .collect()
+            }),
+            None => chunk
+                .iter()
+                .map(|(_, event)| verify_event_id(event, &canonicalizer))
+                .collect(),
+        };
+        for ((offset, event), id_check) in chunk.iter().zip(id_checks) {
+            on_result(checks.check(event, *offset, id_check)?);
+        }
+    }
+
+    if options.require_complete {
+        match reader.last_footer() {
+            None => eprintln!("Warning: journal has no footer; completeness not checked"),
+            Some(_) if reader.is_complete() => {}
+            Some(footer) => {
+                return Err(format!(
+                    "Journal is incomplete: events follow the last footer ({} events)",
+                    footer.event_count
+                )
+                .into());
             }
         }
-        event_count += 1;
+    }
+
+    Ok(())
+}
 
+/// Checks that depend on the events before them, run in journal order.
+struct OrderedChecks<'a> {
+    options: &'a VerifyOptions,
+    seen_ids: HashSet<[u8; 32]>,
+    chain: Option<ChainVerifier<'a>>,
+    order: Option<TemporalOrderChecker>,
+    journal_profile: Option<Option<String>>,
+}
+
+impl OrderedChecks<'_> {
+    /// Builds the result for the next event, given its event_id check.
+    fn check(
+        &mut self,
+        event: &EventJson,
+        offset: u64,
+        id_check: Result<bool, JournalError>,
+    ) -> Result<EventResult, Box<dyn std::error::Error>> {
+        let options = self.options;
         let event_id_str = event
             .get("event_id")
             .and_then(|v| v.get("b64"))
@@ -58,48 +318,186 @@ pub fn run(
             .unwrap_or("?")
             .to_string();
 
-        match verify_event_id(&event, &canonicalizer) {
-            Ok(true) => {
-                results.push((event_id_str.clone(), true, None));
+        let mut result = EventResult::valid(event_id_str);
+        match id_check {
+            Ok(true) => {}
+            Ok(false) if options.reject_tampered => {
+                return Err(format!(
+                    "Tampered event {} at offset {}: event_id mismatch",
+                    result.event_id, offset
+                )
+                .into());
+            }
+            Ok(false) => result.fail("event_id_mismatch", "event_id mismatch".to_string()),
+            Err(e) => result.fail("invalid_event_id", e.to_string()),
+        }
+
+        let id_bytes = event
+            .get("event_id")
+            .and_then(|value| serde_json::from_value::<Digest>(value.clone()).ok())
+            .and_then(|digest| digest.to_bytes());
+        if id_bytes.is_some_and(|bytes| !self.seen_ids.insert(bytes)) {
+            result.fail(
+                "duplicate_event_id",
+                "duplicate event_id (replayed or colliding event)".to_string(),
+            );
+        }
+
+        if let Some(chain) = self.chain.as_mut() {
+            match chain.check(event) {
+                Ok(None) => {}
+                Ok(Some(chain_break)) => result.fail(
+                    "chain_break",
+                    format!(
+                        "prev_event_id chain break: expected {}, found {}",
+                        digest_label(chain_break.expected.as_ref()),
+                        digest_label(chain_break.found.as_ref())
+                    ),
+                ),
+                Err(e) => result.fail("invalid_prev_event_id", e.to_string()),
             }
-            Ok(false) => {
-                all_ok = false;
-                results.push((event_id_str.clone(), false, Some("event_id mismatch".to_string())));
+        }
+
+        if let Some(order) = self.order.as_mut() {
+            match order.check(event) {
+                None => {}
+                Some(OrderViolation::InvalidTimestamp) => result.fail(
+                    "invalid_occurred_at",
+                    "missing or invalid occurred_at".to_string(),
+                ),
+                Some(OrderViolation::Regressed { by_ms }) => result.fail(
+                    "occurred_at_regressed",
+                    format!("occurred_at precedes previous event by {} ms", by_ms),
+                ),
             }
-            Err(e) => {
-                all_ok = false;
-                results.push((event_id_str.clone(), false, Some(e.to_string())));
+        }
+
+        if options.profile_check {
+            let profile = event
+                .get("canonical_profile_id")
+                .and_then(|v| v.as_str())
+                .map(str::to_string);
+            let expected = self.journal_profile.get_or_insert_with(|| profile.clone());
+            if profile != *expected {
+                result.warn(
+                    "profile_mismatch",
+                    format!(
+                        "canonical_profile_id {} differs from journal profile {}",
+                        profile.as_deref().unwrap_or("none"),
+                        expected.as_deref().unwrap_or("none")
+                    ),
+                );
             }
         }
+
+        Ok(result)
     }
+}
+
+/// Renders verification results as a table or a JSON array.
+///
+/// JSON rows carry a `reason` code for their error, or for their warning
+/// when the event is valid.
+///
+/// With `failures_only`, only invalid events are listed and a summary of the
+/// total, valid, and invalid counts is appended; JSON output then becomes an
+/// object with `summary` and `results` keys.
+fn format_report(
+    results: &[EventResult],
+    json_output: bool,
+    failures_only: bool,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let mut counts = VerifyCounts::default();
+    results.iter().for_each(|result| counts.record(result));
+    let listed: Vec<_> = results
+        .iter()
+        .filter(|result| !failures_only || !result.valid)
+        .collect();
 
-    // Output results
+    let mut out = String::new();
     if json_output {
-        let json_results: Vec<_> = results
-            .into_iter()
-            .map(|(id, valid, error)| {
-                json!({
-                    "event_id": id,
-                    "valid": valid,
-                    "error": error
-                })
+        let json_results: Vec<_> = listed
+            .iter()
+            .map(|result| {
+                let mut value = json!({
+                    "event_id": result.event_id,
+                    "valid": result.valid,
+                    "reason": result.reason.or(result.warning_reason),
+                    "error": result.error
+                });
+                if let Some(warning) = &result.warning {
+                    value["warning"] = json!(warning);
+                }
+                value
             })
             .collect();
-        println!("{}", serde_json::to_string_pretty(&json_results)?);
+        let report = if failures_only {
+            json!({
+                "summary": summary_json(&counts),
+                "results": json_results
+            })
+        } else {
+            json!(json_results)
+        };
+        out.push_str(&serde_json::to_string_pretty(&report)?);
+        out.push('\n');
     } else {
-        println!("{:<44} {:<10} ERROR", "EVENT_ID", "VALID");
-        println!("{}", "-".repeat(80));
-        for (id, valid, error_opt) in results {
-            let error_str = error_opt.as_deref().unwrap_or("");
-            println!("{:<44} {:<10} {}", truncate(&id, 44), if valid { "✓" } else { "✗" }, error_str);
+        out.push_str(&format!("{:<44} {:<10} ERROR\n", "EVENT_ID", "VALID"));
+        out.push_str(&format!("{}\n", "-".repeat(80)));
+        for result in listed {
+            let error_str = match (&result.error, &result.warning) {
+                (Some(error), _) => error.clone(),
+                (None, Some(warning)) => format!("warning: {}", warning),
+                (None, None) => String::new(),
+            };
+            out.push_str(&format!(
+                "{:<44} {:<10} {}\n",
+                truncate(&result.event_id, 44),
+                if result.valid { "✓" } else { "✗" },
+                error_str
+            ));
+        }
+        if failures_only {
+            out.push('\n');
+            out.push_str(&summary_line(&counts));
         }
     }
 
-    if strict && !all_ok {
-        std::process::exit(1);
+    Ok(out)
+}
+
+/// Renders only the counts, as a `summary` object or a single line.
+fn format_counts(
+    counts: &VerifyCounts,
+    json_output: bool,
+) -> Result<String, Box<dyn std::error::Error>> {
+    if json_output {
+        let report = json!({ "summary": summary_json(counts) });
+        Ok(format!("{}\n", serde_json::to_string_pretty(&report)?))
+    } else {
+        Ok(summary_line(counts))
     }
+}
 
-    Ok(())
+fn summary_json(counts: &VerifyCounts) -> serde_json::Value {
+    json!({
+        "total": counts.total,
+        "valid": counts.valid(),
+        "invalid": counts.invalid
+    })
+}
+
+fn summary_line(counts: &VerifyCounts) -> String {
+    format!(
+        "{} events: {} valid, {} invalid\n",
+        counts.total,
+        counts.valid(),
+        counts.invalid
+    )
+}
+
+fn digest_label(digest: Option<&Digest>) -> &str {
+    digest.map_or("none", |digest| digest.b64.as_str())
 }
 
 fn truncate(s: &str, max_len: usize) -> String {
@@ -109,3 +507,351 @@ fn truncate(s: &str, max_len: usize) -> String {
         format!("{}...", &s[..max_len.saturating_sub(3)])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use northroot_canonical::compute_event_id;
+    use northroot_journal::{JournalWriter, WriteOptions};
+    use serde_json::Value;
+    use tempfile::TempDir;
+
+    fn signed_event(name: &str) -> Value {
+        sign(json!({
+            "event_type": name,
+            "event_version": "1",
+            "occurred_at": "2024-01-01T00:00:00Z",
+            "principal_id": "service:test",
+            "canonical_profile_id": "northroot-canonical-v1"
+        }))
+    }
+
+    fn sign(mut event: Value) -> Value {
+        let canonicalizer = Canonicalizer::new(ProfileId::parse("northroot-canonical-v1").unwrap());
+        let event_id = compute_event_id(&event, &canonicalizer).unwrap();
+        event["event_id"] = serde_json::to_value(event_id).unwrap();
+        event
+    }
+
+    fn write_journal(path: &Path, events: &[Value]) {
+        let mut writer = JournalWriter::open(path, WriteOptions::default()).unwrap();
+        for event in events {
+            writer.append_event(event).unwrap();
+        }
+        writer.finish().unwrap();
+    }
+
+    #[test]
+    fn flags_second_occurrence_of_duplicate_event_id() {
+        let temp = TempDir::new().unwrap();
+        let journal = temp.path().join("events.nrj");
+        let event = signed_event("test");
+        write_journal(&journal, &[event.clone(), signed_event("other"), event]);
+
+        let results = verify_journal(&journal, &VerifyOptions::default()).unwrap();
+
+        assert_eq!(results.len(), 3);
+        assert!(results[0].valid);
+        assert!(results[1].valid);
+        assert!(!results[2].valid);
+        assert_eq!(results[2].event_id, results[0].event_id);
+        assert!(results[2]
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("duplicate event_id"));
+    }
+
+    #[test]
+    fn reject_tampered_aborts_on_first_mismatch() {
+        let temp = TempDir::new().unwrap();
+        let journal = temp.path().join("events.nrj");
+        let mut tampered = signed_event("tampered");
+        tampered["principal_id"] = json!("service:other");
+        write_journal(
+            &journal,
+            &[signed_event("first"), tampered.clone(), tampered],
+        );
+
+        let mut reader = JournalReader::open(&journal, ReadMode::Strict).unwrap();
+        reader.read_event().unwrap();
+        let expected_offset = reader.position();
+
+        let results = verify_journal(&journal, &VerifyOptions::default()).unwrap();
+        assert_eq!(results.len(), 3);
+
+        let options = VerifyOptions {
+            reject_tampered: true,
+            ..VerifyOptions::default()
+        };
+        let err = verify_journal(&journal, &options).unwrap_err().to_string();
+        assert!(err.contains("event_id mismatch"));
+        assert!(err.contains(&format!("at offset {}", expected_offset)));
+
+        let journal = journal.to_str().unwrap().to_string();
+        assert!(run(journal, false, false, options).is_err());
+    }
+
+    #[test]
+    fn require_complete_checks_trailing_footer() {
+        let temp = TempDir::new().unwrap();
+        let journal = temp.path().join("events.nrj");
+        let options = VerifyOptions {
+            require_complete: true,
+            ..VerifyOptions::default()
+        };
+        let footed = WriteOptions {
+            write_footer: true,
+            ..WriteOptions::default()
+        };
+        for name in ["first", "second"] {
+            let mut writer = JournalWriter::open(&journal, footed.clone()).unwrap();
+            writer.append_event(&signed_event(name)).unwrap();
+            writer.finish().unwrap();
+        }
+
+        assert_eq!(verify_journal(&journal, &options).unwrap().len(), 2);
+
+        // Cut the trailing footer (8-byte frame header + 72-byte payload),
+        // leaving the first session's footer followed by an event.
+        let len = std::fs::metadata(&journal).unwrap().len();
+        std::fs::OpenOptions::new()
+            .write(true)
+            .open(&journal)
+            .unwrap()
+            .set_len(len - 80)
+            .unwrap();
+
+        assert!(verify_journal(&journal, &VerifyOptions::default()).is_ok());
+        let err = verify_journal(&journal, &options).unwrap_err().to_string();
+        assert!(err.contains("Journal is incomplete"));
+    }
+
+    #[test]
+    fn require_complete_skips_journals_without_footer() {
+        let temp = TempDir::new().unwrap();
+        let journal = temp.path().join("events.nrj");
+        write_journal(&journal, &[signed_event("first")]);
+        let options = VerifyOptions {
+            require_complete: true,
+            ..VerifyOptions::default()
+        };
+
+        assert_eq!(verify_journal(&journal, &options).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn chain_mode_flags_broken_prev_event_id_links() {
+        let temp = TempDir::new().unwrap();
+        let journal = temp.path().join("events.nrj");
+        let first = signed_event("first");
+        let second = sign(json!({
+            "event_type": "second",
+            "event_version": "1",
+            "prev_event_id": first["event_id"].clone()
+        }));
+        let spliced = sign(json!({
+            "event_type": "spliced",
+            "event_version": "1",
+            "prev_event_id": first["event_id"].clone()
+        }));
+        write_journal(&journal, &[first, second, spliced]);
+
+        let default = verify_journal(&journal, &VerifyOptions::default()).unwrap();
+        assert!(default.iter().all(|result| result.valid));
+
+        let options = VerifyOptions {
+            chain: true,
+            ..VerifyOptions::default()
+        };
+        let results = verify_journal(&journal, &options).unwrap();
+        assert!(results[0].valid);
+        assert!(results[1].valid);
+        assert!(!results[2].valid);
+        assert!(results[2]
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("prev_event_id chain break"));
+    }
+
+    #[test]
+    fn check_order_flags_events_beyond_skew() {
+        let temp = TempDir::new().unwrap();
+        let journal = temp.path().join("events.nrj");
+        let at = |name: &str, occurred_at: &str| {
+            sign(json!({
+                "event_type": name,
+                "event_version": "1",
+                "occurred_at": occurred_at
+            }))
+        };
+        write_journal(
+            &journal,
+            &[
+                at("first", "2024-01-01T00:00:01Z"),
+                at("skewed", "2024-01-01T00:00:00.900Z"),
+                at("late", "2024-01-01T00:00:00Z"),
+            ],
+        );
+
+        let default = verify_journal(&journal, &VerifyOptions::default()).unwrap();
+        assert!(default.iter().all(|result| result.valid));
+
+        let options = VerifyOptions {
+            order_tolerance_ms: Some(100),
+            ..VerifyOptions::default()
+        };
+        let results = verify_journal(&journal, &options).unwrap();
+        assert!(results[0].valid);
+        assert!(results[1].valid);
+        assert!(!results[2].valid);
+        assert_eq!(
+            results[2].error.as_deref(),
+            Some("occurred_at precedes previous event by 900 ms")
+        );
+    }
+
+    #[test]
+    fn failures_only_lists_invalid_events_with_summary() {
+        let temp = TempDir::new().unwrap();
+        let journal = temp.path().join("events.nrj");
+        let mut tampered = signed_event("tampered");
+        tampered["principal_id"] = json!("service:other");
+        write_journal(
+            &journal,
+            &[signed_event("first"), tampered, signed_event("third")],
+        );
+        let results = verify_journal(&journal, &VerifyOptions::default()).unwrap();
+
+        let report = format_report(&results, true, true).unwrap();
+        let report: Value = serde_json::from_str(&report).unwrap();
+        assert_eq!(
+            report["summary"],
+            json!({"total": 3, "valid": 2, "invalid": 1})
+        );
+        let listed = report["results"].as_array().unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0]["event_id"], results[1].event_id.as_str());
+        assert_eq!(listed[0]["error"], "event_id mismatch");
+
+        let table = format_report(&results, false, true).unwrap();
+        assert_eq!(table.matches('✓').count(), 0);
+        assert_eq!(table.matches('✗').count(), 1);
+        assert!(table.ends_with("3 events: 2 valid, 1 invalid\n"));
+
+        let full = format_report(&results, true, false).unwrap();
+        let full: Value = serde_json::from_str(&full).unwrap();
+        assert_eq!(full.as_array().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn profile_check_warns_on_mixed_profiles() {
+        let temp = TempDir::new().unwrap();
+        let journal = temp.path().join("events.nrj");
+        let mut foreign = signed_event("foreign");
+        foreign["canonical_profile_id"] = json!("other-profile-v1");
+        let foreign = sign(foreign);
+        write_journal(
+            &journal,
+            &[signed_event("first"), foreign, signed_event("third")],
+        );
+
+        let default = verify_journal(&journal, &VerifyOptions::default()).unwrap();
+        assert!(default.iter().all(|result| result.warning.is_none()));
+
+        let options = VerifyOptions {
+            profile_check: true,
+            ..VerifyOptions::default()
+        };
+        let results = verify_journal(&journal, &options).unwrap();
+        assert!(results.iter().all(|result| result.valid));
+        assert!(results[0].warning.is_none());
+        assert_eq!(
+            results[1].warning.as_deref(),
+            Some(
+                "canonical_profile_id other-profile-v1 differs from journal profile \
+                 northroot-canonical-v1"
+            )
+        );
+        assert!(results[2].warning.is_none());
+
+        let report = format_report(&results, true, false).unwrap();
+        let report: Value = serde_json::from_str(&report).unwrap();
+        assert!(report[0].get("warning").is_none());
+        assert!(report[1]["warning"].as_str().is_some());
+    }
+
+    #[test]
+    fn count_only_tallies_match_full_results() {
+        let temp = TempDir::new().unwrap();
+        let journal = temp.path().join("events.nrj");
+        let mut tampered = signed_event("tampered");
+        tampered["principal_id"] = json!("service:other");
+        let first = signed_event("first");
+        write_journal(
+            &journal,
+            &[first.clone(), tampered, signed_event("third"), first],
+        );
+        let options = VerifyOptions::default();
+
+        let results = verify_journal(&journal, &options).unwrap();
+        let mut expected = VerifyCounts::default();
+        results.iter().for_each(|result| expected.record(result));
+        let mut counts = VerifyCounts::default();
+        verify_journal_with(&journal, &options, |result| counts.record(&result)).unwrap();
+        assert_eq!(counts, expected);
+        assert_eq!((counts.total, counts.invalid), (4, 2));
+
+        let report: Value = serde_json::from_str(&format_counts(&counts, true).unwrap()).unwrap();
+        assert_eq!(
+            report,
+            json!({"summary": {"total": 4, "valid": 2, "invalid": 2}})
+        );
+        assert_eq!(
+            format_counts(&counts, false).unwrap(),
+            "4 events: 2 valid, 2 invalid\n"
+        );
+    }
+
+    #[test]
+    fn parallel_results_match_sequential() {
+        let temp = TempDir::new().unwrap();
+        let journal = temp.path().join("events.nrj");
+        let mut events: Vec<Value> = (0..(PARALLEL_CHUNK_SIZE + 100))
+            .map(|i| {
+                sign(json!({
+                    "event_type": "test",
+                    "event_version": "1",
+                    "occurred_at": "2024-01-01T00:00:00Z",
+                    "sequence": i
+                }))
+            })
+            .collect();
+        events[7]["sequence"] = json!("tampered");
+        events[PARALLEL_CHUNK_SIZE + 3]["sequence"] = json!("tampered");
+        events.push(events[0].clone());
+        write_journal(&journal, &events);
+
+        let sequential = VerifyOptions {
+            order_tolerance_ms: Some(0),
+            profile_check: true,
+            ..VerifyOptions::default()
+        };
+        let parallel = VerifyOptions {
+            jobs: 4,
+            ..sequential.clone()
+        };
+        let expected = verify_journal(&journal, &sequential).unwrap();
+        assert_eq!(verify_journal(&journal, &parallel).unwrap(), expected);
+        assert_eq!(expected.len(), events.len());
+        assert_eq!(expected[7].reason, Some("event_id_mismatch"));
+        assert_eq!(expected.last().unwrap().reason, Some("duplicate_event_id"));
+
+        let limited = VerifyOptions {
+            max_events: Some(10),
+            ..parallel
+        };
+        assert_eq!(verify_journal(&journal, &limited).unwrap(), expected[..10]);
+    }
+}
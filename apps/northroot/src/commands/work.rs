@@ -175,6 +175,10 @@ fn ingest_codex(
             sync: options.sync,
             create: true,
             append: true,
+            write_footer: false,
+            frame_crc: false,
+            index: false,
+            compress: None,
         },
     )?;
 
@@ -745,6 +749,10 @@ fn append_snapshot_generated_event(
             sync: true,
             create: true,
             append: true,
+            write_footer: false,
+            frame_crc: false,
+            index: false,
+            compress: None,
         },
     )?;
     writer.append_event(&event)?;
@@ -1911,6 +1919,10 @@ mod tests {
                 sync: false,
                 create: true,
                 append: true,
+                write_footer: false,
+                frame_crc: false,
+                index: false,
+                compress: None,
             },
         )
         .unwrap();
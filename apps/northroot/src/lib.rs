@@ -9,8 +9,8 @@ pub mod path;
 mod test_support;
 
 use commands::{
-    append, canonicalize, event_id, journal, node, read, record, steward, verify, verify_bundle,
-    work,
+    append, canonicalize, chain, event_id, export, import, index, journal, merge, node, read,
+    record, repair, stats, steward, verify, verify_bundle, work,
 };
 
 #[derive(Parser)]
@@ -27,11 +27,17 @@ enum Commands {
     Canonicalize {
         /// Input JSON file (or stdin if not provided)
         input: Option<String>,
+        /// Print the event_id preimage as hex and UTF-8 with the computed event_id
+        #[arg(long)]
+        debug: bool,
     },
     /// Compute event_id for input JSON
     EventId {
         /// Input JSON file (or stdin if not provided)
         input: Option<String>,
+        /// Compare against the input's embedded event_id; exit non-zero on mismatch
+        #[arg(long)]
+        check: bool,
     },
     /// Append an event to a journal
     Append {
@@ -45,6 +51,12 @@ enum Commands {
         /// Sync file to disk after append (default: false)
         #[arg(long)]
         sync: bool,
+        /// Treat input as a JSON array and append every element, or none
+        #[arg(long)]
+        batch: bool,
+        /// Treat input as one JSON event per line and append every line, or none
+        #[arg(long, conflicts_with = "batch")]
+        ndjson: bool,
     },
     /// Read events from a journal
     Read {
@@ -59,6 +71,36 @@ enum Commands {
         /// Reject journals larger than SIZE bytes (default: unlimited)
         #[arg(long)]
         max_size: Option<u64>,
+        /// Show only the event with this event_id (uses the .idx sidecar when present)
+        #[arg(long)]
+        event_id: Option<String>,
+        /// Keep reading, printing events as they are appended
+        #[arg(long, conflicts_with = "event_id")]
+        follow: bool,
+        /// Add each event's frame `_offset` and `_length` in bytes to JSON output
+        #[arg(long, requires = "json", conflicts_with_all = ["event_id", "follow"])]
+        offsets: bool,
+    },
+    /// Summarize a journal: counts by type, principals, occurred_at range, meter totals
+    #[command(hide = true)]
+    Stats {
+        /// Path to journal file
+        journal: String,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+        /// Only count events from this principal_id
+        #[arg(long)]
+        principal: Option<String>,
+        /// Only count events by type, without fully parsing them
+        #[arg(long, conflicts_with = "principal")]
+        types_only: bool,
+    },
+    /// Build an event_id to offset index sidecar for a journal
+    #[command(hide = true)]
+    Index {
+        /// Path to journal file
+        journal: String,
     },
     /// Verify all event IDs in a journal
     Verify {
@@ -76,6 +118,91 @@ enum Commands {
         /// Reject journals larger than SIZE bytes (default: unlimited)
         #[arg(long)]
         max_size: Option<u64>,
+        /// Check that each prev_event_id names the preceding event
+        #[arg(long)]
+        chain: bool,
+        /// Abort with an error on the first event_id mismatch
+        #[arg(long)]
+        reject_tampered: bool,
+        /// Fail unless the journal ends with a matching footer frame
+        #[arg(long)]
+        require_complete: bool,
+        /// Check that occurred_at never decreases between events
+        #[arg(long)]
+        check_order: bool,
+        /// Clock skew tolerated by --check-order, in milliseconds
+        #[arg(long, default_value_t = 0, requires = "check_order")]
+        skew_ms: u64,
+        /// Only list events that fail verification, followed by a summary
+        #[arg(long)]
+        failures_only: bool,
+        /// Print only the valid/invalid counts, without per-event output
+        #[arg(long, conflicts_with = "failures_only")]
+        count_only: bool,
+        /// Warn when canonical_profile_id differs from the first event's (fail with --strict)
+        #[arg(long)]
+        profile_check: bool,
+        /// Recompute event IDs on N threads (output is identical for any N)
+        #[arg(long, default_value_t = 1)]
+        jobs: usize,
+    },
+    /// Show each event's prev_event_id link and flag chain breaks
+    #[command(hide = true)]
+    Chain {
+        /// Path to journal file
+        journal: String,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Export events as JSON lines or CSV, or their event_id preimage bytes
+    #[command(hide = true)]
+    Export {
+        /// Path to journal file
+        journal: String,
+        /// Output format: encoded preimage bytes, one JSON event per line, or CSV
+        #[arg(long, default_value = "preimage", value_parser = ["preimage", "jsonl", "csv"])]
+        format: String,
+        /// Line encoding for canonical bytes (preimage format)
+        #[arg(long, default_value = "hex", value_parser = ["hex", "b64"])]
+        encoding: String,
+    },
+    /// Import one JSON event per line into a journal
+    #[command(hide = true)]
+    Import {
+        /// Input file with one JSON event per line
+        input: String,
+        /// Journal file to append to (created if missing)
+        #[arg(long)]
+        output: String,
+        /// Reject mismatched event_ids and abort on the first malformed line
+        #[arg(long)]
+        strict: bool,
+    },
+    /// Merge journals into one, ordered by occurred_at, dropping duplicate event_ids
+    #[command(hide = true)]
+    Merge {
+        /// Input journal files
+        #[arg(required = true)]
+        inputs: Vec<String>,
+        /// Output journal file
+        #[arg(long)]
+        output: String,
+        /// Overwrite the output file if it exists
+        #[arg(long)]
+        force: bool,
+    },
+    /// Drop a damaged or partial tail, keeping every intact frame byte for byte
+    #[command(hide = true)]
+    Repair {
+        /// Path to journal file
+        journal: String,
+        /// Write the repaired journal here (must not exist)
+        #[arg(long, conflicts_with = "force")]
+        output: Option<String>,
+        /// Repair the journal in place
+        #[arg(long)]
+        force: bool,
     },
     /// Verify a portable evidence bundle
     #[command(hide = true)]
@@ -119,27 +246,87 @@ enum Commands {
 pub fn run_cli() -> Result<(), Box<dyn std::error::Error>> {
     let cli = Cli::parse();
     match cli.command {
-        Commands::Canonicalize { input } => canonicalize::run(input),
-        Commands::EventId { input } => event_id::run(input),
+        Commands::Canonicalize { input, debug } => canonicalize::run(input, debug),
+        Commands::EventId { input, check } => event_id::run(input, check),
         Commands::Append {
             journal,
             input,
             strict,
             sync,
-        } => append::run(journal, input, strict, sync),
+            batch,
+            ndjson,
+        } => append::run(journal, input, strict, sync, batch, ndjson),
         Commands::Read {
             journal,
             json,
             max_events,
             max_size,
-        } => read::run(journal, json, max_events, max_size),
+            event_id,
+            follow,
+            offsets,
+        } => read::run(
+            journal, json, max_events, max_size, event_id, follow, offsets,
+        ),
+        Commands::Stats {
+            journal,
+            json,
+            principal,
+            types_only,
+        } => stats::run(journal, json, principal, types_only),
+        Commands::Index { journal } => index::run(journal),
         Commands::Verify {
             journal,
             strict,
             json,
             max_events,
             max_size,
-        } => verify::run(journal, strict, json, max_events, max_size),
+            chain,
+            reject_tampered,
+            require_complete,
+            check_order,
+            skew_ms,
+            failures_only,
+            count_only,
+            profile_check,
+            jobs,
+        } => verify::run(
+            journal,
+            strict,
+            json,
+            verify::VerifyOptions {
+                max_events,
+                max_size,
+                chain,
+                reject_tampered,
+                require_complete,
+                order_tolerance_ms: check_order.then_some(skew_ms),
+                profile_check,
+                jobs,
+                failures_only,
+                count_only,
+            },
+        ),
+        Commands::Chain { journal, json } => chain::run(journal, json),
+        Commands::Export {
+            journal,
+            format,
+            encoding,
+        } => export::run(journal, format, encoding),
+        Commands::Import {
+            input,
+            output,
+            strict,
+        } => import::run(input, output, strict),
+        Commands::Merge {
+            inputs,
+            output,
+            force,
+        } => merge::run(inputs, output, force),
+        Commands::Repair {
+            journal,
+            output,
+            force,
+        } => repair::run(journal, output, force),
         Commands::VerifyBundle { dir, json } => verify_bundle::run(dir, json),
         Commands::Work { command } => work::run(command),
         Commands::Journal { command } => journal::run(command),
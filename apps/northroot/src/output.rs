@@ -31,6 +31,11 @@ pub fn format_table_row(event: &Value) -> String {
     )
 }
 
+/// Encodes bytes as lowercase hex.
+pub fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
 /// Prints table header.
 #[allow(clippy::print_literal)]
 pub fn print_table_header() {
@@ -1,10 +1,13 @@
-use canonical_json::to_string;
+use canonical_json::{to_string, JsonFormatter};
+use serde::de::{self, DeserializeSeed, MapAccess, SeqAccess, Visitor};
+use serde::Serialize;
 use serde_json::Value;
 
 use crate::hygiene::{HygieneReport, HygieneStatus, HygieneWarning};
 use crate::identifiers::ProfileId;
 use std::collections::BTreeMap;
 use std::fmt;
+use std::io::{BufReader, BufWriter, Read, Write};
 
 /// Error returned when canonicalization fails.
 #[derive(thiserror::Error, Debug)]
@@ -13,10 +16,11 @@ pub enum CanonicalizationError {
     #[error("invalid JSON structure: {0}")]
     InvalidStructure(String),
     /// A duplicate object member was detected.
-    /// Duplicate detection occurs at the strict JSON parsing layer because
-    /// serde_json::Value::Object cannot preserve duplicates after parsing.
+    /// Only [`Canonicalizer::canonicalize_bytes`] and
+    /// [`Canonicalizer::canonicalize_reader`] report this, because they parse
+    /// their input; serde_json::Value::Object cannot preserve duplicates
+    /// after parsing, so other callers must use the strict JSON parsing layer.
     #[error("duplicate key detected at {0}")]
-    #[allow(dead_code)]
     DuplicateKey(String),
     /// Non-finite number (NaN/Infinity) detected.
     #[error("non-finite number detected at {0}")]
@@ -205,6 +209,140 @@ impl Canonicalizer {
         Ok(CanonicalizationResult { bytes, report })
     }
 
+    /// Canonicalizes JSON read from `reader`, writing the canonical bytes to
+    /// `writer`.
+    ///
+    /// Produces the same bytes and hygiene report as parsing the input and
+    /// calling [`canonicalize`](Self::canonicalize), without building a
+    /// [`Value`] tree. Scalars and arrays are written as they are parsed;
+    /// only object members are buffered, as canonical bytes, until their
+    /// object closes and its keys can be sorted. Large documents therefore
+    /// cost roughly their canonical size in memory instead of a parsed tree
+    /// plus its serialization.
+    ///
+    /// Because the input is parsed here, duplicate object keys are visible
+    /// and rejected, as [`parse_json_strict`](crate::parse_json_strict) does.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CanonicalizationError`] if:
+    /// - the input is not valid JSON (`InvalidStructure`)
+    /// - an object repeats a key (`DuplicateKey`)
+    /// - a non-finite number is detected (`NonFiniteNumber`)
+    /// - reading or writing fails (`Other`)
+    ///
+    /// Bytes already written to `writer` are unspecified after an error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use northroot_canonical::{Canonicalizer, ProfileId};
+    ///
+    /// let profile = ProfileId::parse("northroot-canonical-v1")?;
+    /// let canonicalizer = Canonicalizer::new(profile);
+    ///
+    /// let mut bytes = Vec::new();
+    /// let report = canonicalizer.canonicalize_reader(&br#"{"z": [3, 1], "a": 1}"#[..], &mut bytes)?;
+    ///
+    /// assert_eq!(bytes, br#"{"a":1,"z":[3,1]}"#);
+    /// assert_eq!(report.status, northroot_canonical::HygieneStatus::Ok);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn canonicalize_reader<R: Read, W: Write>(
+        &self,
+        reader: R,
+        writer: W,
+    ) -> Result<HygieneReport, CanonicalizationError> {
+        self.canonicalize_stream(reader, writer)
+            .map_err(|(err, _)| err)
+    }
+
+    /// Produces canonical bytes + hygiene report from raw JSON bytes,
+    /// returning the report even on error.
+    ///
+    /// Unlike [`canonicalize`](Self::canonicalize), whose already parsed
+    /// [`Value`] cannot hold duplicate keys, this sees the raw input. An
+    /// object that repeats a key fails with
+    /// [`CanonicalizationError::DuplicateKey`] and a report whose status is
+    /// [`HygieneStatus::Invalid`] with a `DuplicateKeys` warning. Other input
+    /// produces the same bytes and report as parsing it and calling
+    /// [`canonicalize`](Self::canonicalize).
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`CanonicalizationError`] and the `Invalid` report under
+    /// the same conditions as [`canonicalize_reader`](Self::canonicalize_reader).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use northroot_canonical::{Canonicalizer, HygieneStatus, HygieneWarning, ProfileId};
+    ///
+    /// let profile = ProfileId::parse("northroot-canonical-v1")?;
+    /// let canonicalizer = Canonicalizer::new(profile);
+    ///
+    /// let result = canonicalizer.canonicalize_bytes(br#"{"b": 2, "a": 1}"#).unwrap();
+    /// assert_eq!(result.bytes, br#"{"a":1,"b":2}"#);
+    ///
+    /// let (_, report) = canonicalizer.canonicalize_bytes(br#"{"a": 1, "a": 2}"#).unwrap_err();
+    /// assert_eq!(report.status, HygieneStatus::Invalid);
+    /// assert_eq!(report.warnings, [HygieneWarning::new("DuplicateKeys")]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn canonicalize_bytes(
+        &self,
+        raw: &[u8],
+    ) -> Result<CanonicalizationResult, (CanonicalizationError, HygieneReport)> {
+        let mut bytes = Vec::with_capacity(raw.len());
+        let report = self.canonicalize_stream(raw, &mut bytes)?;
+        Ok(CanonicalizationResult { bytes, report })
+    }
+
+    /// Streams `reader` to canonical bytes on `writer`, keeping the report on error.
+    fn canonicalize_stream<R: Read, W: Write>(
+        &self,
+        reader: R,
+        writer: W,
+    ) -> Result<HygieneReport, (CanonicalizationError, HygieneReport)> {
+        let mut state = StreamState {
+            report: HygieneReport {
+                status: HygieneStatus::Ok,
+                warnings: vec![],
+                metrics: BTreeMap::new(),
+                profile_id: self.profile.clone(),
+            },
+            error: None,
+        };
+        let mut writer = BufWriter::new(writer);
+        let mut deserializer = serde_json::Deserializer::from_reader(BufReader::new(reader));
+
+        let result = CanonicalSeed {
+            out: &mut writer,
+            path: Path::root(),
+            state: &mut state,
+            prefix: b"",
+        }
+        .deserialize(&mut deserializer)
+        .and_then(|()| deserializer.end());
+        if let Err(err) = result {
+            let error = state.error.take().unwrap_or_else(|| {
+                if err.is_io() {
+                    CanonicalizationError::Other(err.to_string())
+                } else {
+                    CanonicalizationError::InvalidStructure(err.to_string())
+                }
+            });
+            state.report.status = HygieneStatus::Invalid;
+            return Err((error, state.report));
+        }
+        if let Err(err) = writer.flush() {
+            state.report.status = HygieneStatus::Invalid;
+            return Err((CanonicalizationError::Other(err.to_string()), state.report));
+        }
+
+        Ok(state.report)
+    }
+
     /// Validates the JSON value according to the canonical profile.
     #[allow(clippy::only_used_in_recursion)]
     fn validate(
@@ -218,6 +356,13 @@ impl Canonicalizer {
                 // Note: Duplicate key detection is redundant here because
                 // serde_json::Value::Object is a BTreeMap which cannot have duplicates.
                 // Duplicate detection should happen at the JSON parsing layer, not here.
+                let is_dec = map.get("t").and_then(Value::as_str) == Some("dec");
+                for key in integer_members(is_dec, map.contains_key("digest")) {
+                    if map.get(key).is_some_and(|member| !member.is_u64()) {
+                        report.status = HygieneStatus::Invalid;
+                        return Err(not_an_integer(&path.push_field(key)));
+                    }
+                }
                 for (key, child) in map {
                     self.validate(child, path.push_field(key), report)?;
                 }
@@ -234,12 +379,7 @@ impl Canonicalizer {
                 if num.is_f64() {
                     let f = num.as_f64().unwrap();
                     if !f.is_finite() {
-                        report.warnings.push(HygieneWarning::new("NonFiniteNumber"));
-                        report
-                            .metrics
-                            .entry("non_finite_numbers".to_string())
-                            .and_modify(|count| *count += 1)
-                            .or_insert(1);
+                        record_non_finite(report);
                         return Err(CanonicalizationError::NonFiniteNumber(format!("{}", path)));
                     }
                 }
@@ -264,3 +404,199 @@ impl Canonicalizer {
         }
     }
 }
+
+/// Names the members of an object that must be non-negative JSON integers,
+/// never strings: the scale `s` of a `dec` quantity and the `size_bytes` of a
+/// content reference (an object with a `digest`).
+fn integer_members(is_dec: bool, has_digest: bool) -> impl Iterator<Item = &'static str> {
+    [(is_dec, "s"), (has_digest, "size_bytes")]
+        .into_iter()
+        .filter_map(|(applies, key)| applies.then_some(key))
+}
+
+fn not_an_integer(path: &Path) -> CanonicalizationError {
+    CanonicalizationError::InvalidStructure(format!(
+        "{}: must be a non-negative JSON integer",
+        path
+    ))
+}
+
+/// Records a non-finite number in the hygiene report.
+fn record_non_finite(report: &mut HygieneReport) {
+    report.warnings.push(HygieneWarning::new("NonFiniteNumber"));
+    report
+        .metrics
+        .entry("non_finite_numbers".to_string())
+        .and_modify(|count| *count += 1)
+        .or_insert(1);
+}
+
+/// Hygiene report and first typed error of a streaming canonicalization.
+struct StreamState {
+    report: HygieneReport,
+    error: Option<CanonicalizationError>,
+}
+
+impl StreamState {
+    /// Keeps `error` for the caller and aborts deserialization.
+    fn fail<E: de::Error>(&mut self, error: CanonicalizationError) -> E {
+        let message = error.to_string();
+        self.report.status = HygieneStatus::Invalid;
+        self.error.get_or_insert(error);
+        E::custom(message)
+    }
+}
+
+/// Writes one JSON value's canonical bytes to `out` as it is parsed.
+///
+/// `prefix` (a separating comma, or nothing) is written first, once the
+/// value is known to exist.
+struct CanonicalSeed<'a> {
+    out: &'a mut dyn Write,
+    path: Path,
+    state: &'a mut StreamState,
+    prefix: &'static [u8],
+}
+
+impl CanonicalSeed<'_> {
+    fn write_bytes<E: de::Error>(&mut self, bytes: &[u8]) -> Result<(), E> {
+        self.out.write_all(bytes).map_err(|err| {
+            self.state
+                .fail(CanonicalizationError::Other(err.to_string()))
+        })
+    }
+
+    /// Writes a scalar with the same formatter [`to_string`] uses.
+    fn write_scalar<T: Serialize + ?Sized, E: de::Error>(&mut self, value: &T) -> Result<(), E> {
+        let mut serializer = serde_json::Serializer::with_formatter(&mut *self.out, JsonFormatter);
+        value.serialize(&mut serializer).map_err(|err| {
+            self.state
+                .fail(CanonicalizationError::Other(err.to_string()))
+        })
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for CanonicalSeed<'_> {
+    type Value = ();
+
+    fn deserialize<D>(mut self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        self.write_bytes(self.prefix)?;
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for CanonicalSeed<'_> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("any valid JSON value")
+    }
+
+    fn visit_bool<E: de::Error>(mut self, value: bool) -> Result<Self::Value, E> {
+        self.write_scalar(&value)
+    }
+
+    fn visit_i64<E: de::Error>(mut self, value: i64) -> Result<Self::Value, E> {
+        self.write_scalar(&value)
+    }
+
+    fn visit_u64<E: de::Error>(mut self, value: u64) -> Result<Self::Value, E> {
+        self.write_scalar(&value)
+    }
+
+    fn visit_f64<E: de::Error>(mut self, value: f64) -> Result<Self::Value, E> {
+        if !value.is_finite() {
+            record_non_finite(&mut self.state.report);
+            let path = self.path.to_string();
+            return Err(self
+                .state
+                .fail(CanonicalizationError::NonFiniteNumber(path)));
+        }
+        self.write_scalar(&value)
+    }
+
+    fn visit_str<E: de::Error>(mut self, value: &str) -> Result<Self::Value, E> {
+        self.write_scalar(value)
+    }
+
+    fn visit_unit<E: de::Error>(mut self) -> Result<Self::Value, E> {
+        self.write_bytes(b"null")
+    }
+
+    fn visit_seq<A>(mut self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        self.write_bytes(b"[")?;
+        let mut index = 0;
+        while seq
+            .next_element_seed(CanonicalSeed {
+                out: &mut *self.out,
+                path: self.path.push_index(index),
+                state: &mut *self.state,
+                prefix: if index == 0 { b"" } else { b"," },
+            })?
+            .is_some()
+        {
+            index += 1;
+        }
+        self.write_bytes(b"]")
+    }
+
+    fn visit_map<A>(mut self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        // Members are buffered until the object closes, then written in key order.
+        let mut members: BTreeMap<String, Vec<u8>> = BTreeMap::new();
+        while let Some(key) = map.next_key::<String>()? {
+            let path = self.path.push_field(&key);
+            if members.contains_key(&key) {
+                let report = &mut self.state.report;
+                report.warnings.push(HygieneWarning::new("DuplicateKeys"));
+                *report
+                    .metrics
+                    .entry("duplicate_keys".to_string())
+                    .or_default() += 1;
+                return Err(self
+                    .state
+                    .fail(CanonicalizationError::DuplicateKey(path.to_string())));
+            }
+            let mut value = Vec::new();
+            map.next_value_seed(CanonicalSeed {
+                out: &mut value,
+                path,
+                state: &mut *self.state,
+                prefix: b"",
+            })?;
+            members.insert(key, value);
+        }
+
+        // Members hold canonical bytes, so a non-negative integer is all digits.
+        let is_dec = members
+            .get("t")
+            .is_some_and(|t| t.as_slice() == br#""dec""#);
+        for key in integer_members(is_dec, members.contains_key("digest")) {
+            let is_integer =
+                |bytes: &Vec<u8>| !bytes.is_empty() && bytes.iter().all(|b| b.is_ascii_digit());
+            if members.get(key).is_some_and(|member| !is_integer(member)) {
+                let error = not_an_integer(&self.path.push_field(key));
+                return Err(self.state.fail(error));
+            }
+        }
+
+        self.write_bytes(b"{")?;
+        for (index, (key, value)) in members.iter().enumerate() {
+            if index > 0 {
+                self.write_bytes(b",")?;
+            }
+            self.write_scalar(key.as_str())?;
+            self.write_bytes(b":")?;
+            self.write_bytes(value)?;
+        }
+        self.write_bytes(b"}")
+    }
+}
@@ -13,6 +13,22 @@ pub enum DigestAlg {
     Sha256,
 }
 
+impl DigestAlg {
+    /// Algorithm name as serialized, e.g. `sha-256`.
+    pub fn name(self) -> &'static str {
+        match self {
+            DigestAlg::Sha256 => "sha-256",
+        }
+    }
+
+    /// Length of the digest in unpadded base64url characters.
+    fn encoded_len(self) -> usize {
+        match self {
+            DigestAlg::Sha256 => 43,
+        }
+    }
+}
+
 /// Algorithm + bytes digest, encoded as base64url without padding.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Digest {
@@ -25,17 +41,74 @@ pub struct Digest {
 
 impl Digest {
     /// Constructs a validated digest.
+    ///
+    /// `b64` must be unpadded base64url that decodes to the algorithm's
+    /// output length (32 bytes, or 43 characters, for SHA-256), with unused
+    /// trailing bits set to zero so that each digest has one encoding.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValidationError::InvalidDigest`] describing the first
+    /// problem found: characters outside the base64url alphabet, the wrong
+    /// length, or a non-canonical final character.
     pub fn new(alg: DigestAlg, b64: impl Into<String>) -> Result<Self, ValidationError> {
+        use base64::Engine;
         let b64 = b64.into();
-        let re = Regex::new(r"^[A-Za-z0-9_-]{43,44}$").expect("invalid regex");
+        let invalid =
+            |value: String, reason: String| ValidationError::InvalidDigest { value, reason };
+
+        let re = Regex::new(r"^[A-Za-z0-9_-]*$").expect("invalid regex");
         if !re.is_match(&b64) {
-            return Err(ValidationError::PatternMismatch {
-                field: "digest",
-                value: b64,
-            });
+            return Err(invalid(
+                b64,
+                "contains characters outside the unpadded base64url alphabet".to_string(),
+            ));
+        }
+        let expected = alg.encoded_len();
+        if b64.len() != expected {
+            let reason = format!(
+                "expected {} characters for {}, found {}",
+                expected,
+                alg.name(),
+                b64.len()
+            );
+            return Err(invalid(b64, reason));
+        }
+        if base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(&b64)
+            .is_err()
+        {
+            return Err(invalid(
+                b64,
+                "final character has non-zero trailing bits".to_string(),
+            ));
         }
         Ok(Digest { alg, b64 })
     }
+
+    /// Constructs a digest from raw 32-byte hash output.
+    pub fn from_bytes(alg: DigestAlg, bytes: &[u8; 32]) -> Self {
+        use base64::Engine;
+        Digest {
+            alg,
+            b64: base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(bytes),
+        }
+    }
+
+    /// Decodes the raw 32-byte hash output.
+    ///
+    /// Fixed-size arrays make cheaper map keys than the base64 string when
+    /// tracking large numbers of digests. Returns `None` if `b64` does not
+    /// decode to exactly 32 bytes (possible for values built with struct
+    /// literals or deserialized without going through `new`).
+    pub fn to_bytes(&self) -> Option<[u8; 32]> {
+        use base64::Engine;
+        base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(&self.b64)
+            .ok()?
+            .try_into()
+            .ok()
+    }
 }
 
 /// Computes the canonical raw-byte blob digest for immutable external content.
@@ -45,9 +118,60 @@ impl Digest {
 pub fn compute_blob_digest(bytes: &[u8]) -> Result<Digest, ValidationError> {
     let mut hasher = Sha256::new();
     hasher.update(bytes);
-    let hash_bytes = hasher.finalize();
+    Ok(Digest::from_bytes(
+        DigestAlg::Sha256,
+        &hasher.finalize().into(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    use base64::Engine;
-    let b64 = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(hash_bytes);
-    Digest::new(DigestAlg::Sha256, b64)
+    #[test]
+    fn raw_bytes_round_trip_with_base64() {
+        let digest = compute_blob_digest(b"northroot").unwrap();
+        let bytes = digest.to_bytes().unwrap();
+        assert_eq!(bytes.as_slice(), Sha256::digest(b"northroot").as_slice());
+        assert_eq!(Digest::from_bytes(DigestAlg::Sha256, &bytes), digest);
+
+        let unchecked = Digest {
+            alg: DigestAlg::Sha256,
+            b64: "A".repeat(44),
+        };
+        assert_eq!(unchecked.to_bytes(), None);
+    }
+
+    #[test]
+    fn new_rejects_malformed_base64url() {
+        let valid = compute_blob_digest(b"northroot").unwrap();
+        assert_eq!(
+            Digest::new(DigestAlg::Sha256, valid.b64.clone()).unwrap(),
+            valid
+        );
+
+        let reason = |b64: &str| match Digest::new(DigestAlg::Sha256, b64) {
+            Err(ValidationError::InvalidDigest { reason, .. }) => reason,
+            other => panic!("expected InvalidDigest, got {:?}", other),
+        };
+        assert_eq!(
+            reason(&"A".repeat(44)),
+            "expected 43 characters for sha-256, found 44"
+        );
+        assert_eq!(reason("abc"), "expected 43 characters for sha-256, found 3");
+        assert_eq!(reason(""), "expected 43 characters for sha-256, found 0");
+        assert_eq!(
+            reason(&format!("{}+", "A".repeat(42))),
+            "contains characters outside the unpadded base64url alphabet"
+        );
+        assert_eq!(
+            reason(&format!("{}=", "A".repeat(43))),
+            "contains characters outside the unpadded base64url alphabet"
+        );
+        // 43 characters carry 258 bits; the last two must be zero.
+        assert_eq!(
+            reason(&format!("{}B", "A".repeat(42))),
+            "final character has non-zero trailing bits"
+        );
+    }
 }
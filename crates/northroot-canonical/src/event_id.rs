@@ -8,8 +8,9 @@ use serde::Serialize;
 use serde_json::Value;
 use sha2::{Digest as Sha2Digest, Sha256};
 
-/// Domain separator for event ID computation: `b"northroot:event:v1\0"`.
-const EVENT_DOMAIN_SEPARATOR: &[u8] = b"northroot:event:v1\0";
+/// Default event ID domain. It is hashed NUL-terminated, so the domain
+/// separator is `b"northroot:event:v1\0"`.
+pub const DEFAULT_EVENT_DOMAIN: &str = "northroot:event:v1";
 
 /// Computes the event ID for a canonical event.
 ///
@@ -53,6 +54,116 @@ pub fn compute_event_id<T: Serialize>(
     event: &T,
     canonicalizer: &Canonicalizer,
 ) -> Result<Digest, EventIdError> {
+    compute_event_id_with(event, canonicalizer, &Sha256Hasher)
+}
+
+/// Computes the event ID for a canonical event under a custom domain.
+///
+/// Formula: `sha256(domain || 0x00 || canonical_bytes(event))`. With
+/// [`DEFAULT_EVENT_DOMAIN`] this is exactly [`compute_event_id`]; isolated
+/// deployments can pass their own domain so that identical content never
+/// shares an ID across environments.
+///
+/// Changing the domain changes every event ID. Events identified under a
+/// custom domain do not verify with [`verify_event_id`], which always uses
+/// the default domain.
+///
+/// # Example
+///
+/// ```rust
+/// use northroot_canonical::{
+///     compute_event_id, compute_event_id_with_domain, Canonicalizer, ProfileId,
+///     DEFAULT_EVENT_DOMAIN,
+/// };
+/// use serde_json::json;
+///
+/// let canonicalizer = Canonicalizer::new(ProfileId::parse("northroot-canonical-v1")?);
+/// let event = json!({"event_type": "test", "event_version": "1"});
+///
+/// let staging = compute_event_id_with_domain(&event, &canonicalizer, "acme:staging:v1")?;
+/// assert_ne!(staging, compute_event_id(&event, &canonicalizer)?);
+/// assert_eq!(
+///     compute_event_id_with_domain(&event, &canonicalizer, DEFAULT_EVENT_DOMAIN)?,
+///     compute_event_id(&event, &canonicalizer)?
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns [`EventIdError::InvalidDomain`] if `domain` is empty or contains
+/// a NUL byte, or [`EventIdError`] if serialization or canonicalization fails.
+pub fn compute_event_id_with_domain<T: Serialize>(
+    event: &T,
+    canonicalizer: &Canonicalizer,
+    domain: &str,
+) -> Result<Digest, EventIdError> {
+    if domain.is_empty() || domain.contains('\0') {
+        return Err(EventIdError::InvalidDomain(
+            "domain must be non-empty and contain no NUL bytes".to_string(),
+        ));
+    }
+    let bytes = event_id_preimage(event, canonicalizer)?;
+    Ok(Sha256Hasher.hash(&hash_input(domain, &bytes)))
+}
+
+/// Hash function used to derive event IDs.
+///
+/// Implementations receive the complete hash input,
+/// `domain_separator || canonical_bytes(event)`, and return its digest. This
+/// lets integrators route hashing through accelerated or HSM-backed
+/// implementations without changing canonicalization.
+pub trait EventHasher {
+    /// Hashes the event ID input bytes.
+    fn hash(&self, bytes: &[u8]) -> Digest;
+}
+
+/// Default [`EventHasher`]: SHA-256 in software.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Sha256Hasher;
+
+impl EventHasher for Sha256Hasher {
+    fn hash(&self, bytes: &[u8]) -> Digest {
+        use base64::Engine;
+        Digest {
+            alg: DigestAlg::Sha256,
+            b64: base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(Sha256::digest(bytes)),
+        }
+    }
+}
+
+/// Computes the event ID for a canonical event using a caller-supplied hasher.
+///
+/// Identical to [`compute_event_id`] except that the domain-separated
+/// canonical bytes are passed to `hasher`. With [`Sha256Hasher`] the result
+/// is the standard event ID.
+///
+/// # Errors
+///
+/// Returns [`EventIdError`] if serialization or canonicalization fails.
+pub fn compute_event_id_with<T: Serialize, H: EventHasher + ?Sized>(
+    event: &T,
+    canonicalizer: &Canonicalizer,
+    hasher: &H,
+) -> Result<Digest, EventIdError> {
+    let bytes = event_id_preimage(event, canonicalizer)?;
+    Ok(hasher.hash(&hash_input(DEFAULT_EVENT_DOMAIN, &bytes)))
+}
+
+/// Returns the canonical bytes that an event's ID is computed over.
+///
+/// These are the canonical bytes of the event with any `event_id` field
+/// removed. Passing them to [`event_id_from_canonical_bytes`] yields the same
+/// digest as [`compute_event_id`], so exported preimages can be verified
+/// without re-canonicalizing.
+///
+/// # Errors
+///
+/// Returns [`EventIdError`] if serialization or canonicalization fails.
+pub fn event_id_preimage<T: Serialize>(
+    event: &T,
+    canonicalizer: &Canonicalizer,
+) -> Result<Vec<u8>, EventIdError> {
     // Serialize to JSON Value first
     let mut value: Value =
         serde_json::to_value(event).map_err(|e| EventIdError::Serialization(e.to_string()))?;
@@ -62,18 +173,50 @@ pub fn compute_event_id<T: Serialize>(
         map.remove("event_id");
     }
 
-    // Canonicalize the JSON value
-    let result = canonicalizer.canonicalize(&value)?;
+    Ok(canonicalizer.canonicalize(&value)?.bytes)
+}
 
-    // Hash: domain_separator || canonical_bytes
-    let mut hasher = Sha256::new();
-    hasher.update(EVENT_DOMAIN_SEPARATOR);
-    hasher.update(&result.bytes);
-    let hash_bytes = hasher.finalize();
+/// Computes an event ID from already-canonical preimage bytes.
+///
+/// Formula: `sha256(domain_separator || canonical_bytes)`. The bytes are
+/// hashed as given; callers are responsible for producing them with
+/// [`event_id_preimage`].
+///
+/// # Example
+///
+/// ```rust
+/// use northroot_canonical::{
+///     compute_event_id, event_id_from_canonical_bytes, event_id_preimage, Canonicalizer,
+///     ProfileId,
+/// };
+/// use serde_json::json;
+///
+/// let canonicalizer = Canonicalizer::new(ProfileId::parse("northroot-canonical-v1")?);
+/// let event = json!({"event_type": "test", "event_version": "1"});
+///
+/// let bytes = event_id_preimage(&event, &canonicalizer)?;
+/// assert_eq!(
+///     event_id_from_canonical_bytes(&bytes)?,
+///     compute_event_id(&event, &canonicalizer)?
+/// );
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns [`EventIdError::Digest`] if the digest cannot be constructed.
+pub fn event_id_from_canonical_bytes(bytes: &[u8]) -> Result<Digest, EventIdError> {
+    let digest = Sha256Hasher.hash(&hash_input(DEFAULT_EVENT_DOMAIN, bytes));
+    Ok(Digest::new(digest.alg, digest.b64)?)
+}
 
-    use base64::Engine;
-    let b64 = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(hash_bytes);
-    Ok(Digest::new(DigestAlg::Sha256, b64)?)
+/// Hash input: domain || 0x00 || canonical_bytes
+fn hash_input(domain: &str, canonical_bytes: &[u8]) -> Vec<u8> {
+    let mut input = Vec::with_capacity(domain.len() + 1 + canonical_bytes.len());
+    input.extend_from_slice(domain.as_bytes());
+    input.push(0);
+    input.extend_from_slice(canonical_bytes);
+    input
 }
 
 /// Error during event ID computation.
@@ -88,6 +231,9 @@ pub enum EventIdError {
     /// Digest construction failed.
     #[error("digest construction failed: {0}")]
     Digest(#[from] crate::ValidationError),
+    /// A custom event ID domain was rejected.
+    #[error("invalid event ID domain: {0}")]
+    InvalidDomain(String),
 }
 
 /// Verifies that a claimed event_id matches the computed event_id.
@@ -186,4 +332,52 @@ mod tests {
 
         assert_ne!(numeric_id, string_id);
     }
+
+    struct FixedHasher;
+
+    impl EventHasher for FixedHasher {
+        fn hash(&self, bytes: &[u8]) -> Digest {
+            assert!(bytes.starts_with(b"northroot:event:v1\0"));
+            Digest::new(DigestAlg::Sha256, "A".repeat(43)).unwrap()
+        }
+    }
+
+    #[test]
+    fn compute_event_id_with_uses_supplied_hasher() {
+        let canonicalizer = canonicalizer();
+        let event = json!({
+            "event_type": "test",
+            "event_version": "1",
+            "event_id": {"alg": "sha-256", "b64": "ignored"}
+        });
+
+        let fixed = compute_event_id_with(&event, &canonicalizer, &FixedHasher).unwrap();
+        assert_eq!(fixed.b64, "A".repeat(43));
+
+        let default = compute_event_id_with(&event, &canonicalizer, &Sha256Hasher).unwrap();
+        assert_eq!(default, compute_event_id(&event, &canonicalizer).unwrap());
+    }
+
+    #[test]
+    fn domains_namespace_event_ids() {
+        let event = json!({"event_type": "test", "event_version": "1"});
+        let canonicalizer = canonicalizer();
+
+        let prod = compute_event_id_with_domain(&event, &canonicalizer, "acme:prod:v1").unwrap();
+        let staging =
+            compute_event_id_with_domain(&event, &canonicalizer, "acme:staging:v1").unwrap();
+        assert_ne!(prod, staging);
+        assert_ne!(prod, compute_event_id(&event, &canonicalizer).unwrap());
+        assert_eq!(
+            compute_event_id_with_domain(&event, &canonicalizer, DEFAULT_EVENT_DOMAIN).unwrap(),
+            compute_event_id(&event, &canonicalizer).unwrap()
+        );
+
+        for domain in ["", "acme\0prod"] {
+            assert!(matches!(
+                compute_event_id_with_domain(&event, &canonicalizer, domain),
+                Err(EventIdError::InvalidDomain(_))
+            ));
+        }
+    }
 }
@@ -92,6 +92,79 @@ newtype!(
     r"^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}(\.\d{1,9})?Z$"
 );
 
+impl Timestamp {
+    /// Returns milliseconds since the Unix epoch, truncating sub-millisecond digits.
+    ///
+    /// Returns `None` if a date or time field is out of range (the pattern
+    /// alone admits values such as month `13`) or uses non-ASCII digits,
+    /// which the pattern's `\d` also matches. A leap second (`:60`) is
+    /// accepted and counts as the following second.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use northroot_canonical::Timestamp;
+    ///
+    /// let ts = Timestamp::parse("1970-01-02T00:00:00.5Z")?;
+    /// assert_eq!(ts.unix_millis(), Some(86_400_500));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn unix_millis(&self) -> Option<i64> {
+        let s = self.0.as_str();
+        // Fields are sliced by byte position, which assumes ASCII.
+        if !s.is_ascii() {
+            return None;
+        }
+        let field = |range: std::ops::Range<usize>| s[range].parse::<i64>().ok();
+        let (year, month, day) = (field(0..4)?, field(5..7)?, field(8..10)?);
+        let (hour, minute, second) = (field(11..13)?, field(14..16)?, field(17..19)?);
+        if !(1..=12).contains(&month)
+            || day < 1
+            || day > days_in_month(year, month)
+            || hour > 23
+            || minute > 59
+            || second > 60
+        {
+            return None;
+        }
+
+        // Fraction digits sit between the '.' at index 19 and the trailing 'Z'.
+        let millis = match s.get(20..s.len() - 1) {
+            Some(fraction) if s.as_bytes()[19] == b'.' => {
+                let digits = &fraction[..fraction.len().min(3)];
+                digits.parse::<i64>().ok()? * 10_i64.pow(3 - digits.len() as u32)
+            }
+            _ => 0,
+        };
+
+        let days = days_from_civil(year, month, day);
+        Some(((days * 24 + hour) * 60 + minute) * 60_000 + second * 1000 + millis)
+    }
+}
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -115,4 +188,22 @@ mod tests {
         assert!(Timestamp::try_from("2024-01-01T00:00:00Z").is_ok());
         assert!(Timestamp::try_from("2024-01-01 00:00:00").is_err());
     }
+
+    #[test]
+    fn timestamp_unix_millis() {
+        let millis = |s: &str| Timestamp::parse(s).unwrap().unix_millis();
+        assert_eq!(millis("1970-01-01T00:00:00Z"), Some(0));
+        assert_eq!(millis("2024-01-01T00:00:00Z"), Some(1_704_067_200_000));
+        assert_eq!(
+            millis("2024-02-29T12:30:15.123456789Z"),
+            Some(1_709_209_815_123)
+        );
+        assert_eq!(millis("2024-01-01T00:00:00.05Z"), Some(1_704_067_200_050));
+        assert_eq!(millis("1969-12-31T23:59:59Z"), Some(-1000));
+        assert_eq!(millis("2023-02-29T00:00:00Z"), None);
+        assert_eq!(millis("2024-13-01T00:00:00Z"), None);
+        // `\d` admits other Unicode digits; they must not panic.
+        assert_eq!(millis("202\u{664}-01-01T00:00:00Z"), None);
+        assert_eq!(millis("2024-01-01T00:00:00.\u{664}Z"), None);
+    }
 }
@@ -40,7 +40,9 @@
 //!
 //! - [`Canonicalizer`] - Produces deterministic canonical bytes from JSON
 //! - [`compute_event_id`] - Computes content-derived event identifiers
+//! - [`compute_event_id_with`] - Same, with a caller-supplied [`EventHasher`]
 //! - [`Quantity`] - Lossless numeric types (Dec, Int, Rat, F64)
+//! - [`compare_quantities`] - Exact same-variant quantity comparison
 //! - [`Digest`] - Content-addressed identifiers
 //! - [`PrincipalId`], [`ProfileId`], [`Timestamp`] - Core identifier types
 //!
@@ -71,9 +73,13 @@ pub mod validation;
 
 pub use canonicalizer::{CanonicalizationError, CanonicalizationResult, Canonicalizer};
 pub use digest::{compute_blob_digest, Digest, DigestAlg};
-pub use event_id::{compute_event_id, verify_event_id, EventIdError};
+pub use event_id::{
+    compute_event_id, compute_event_id_with, compute_event_id_with_domain,
+    event_id_from_canonical_bytes, event_id_preimage, verify_event_id, EventHasher, EventIdError,
+    Sha256Hasher, DEFAULT_EVENT_DOMAIN,
+};
 pub use hygiene::{HygieneReport, HygieneStatus, HygieneWarning};
 pub use identifiers::{ContentRef, PrincipalId, ProfileId, Timestamp, ToolName};
 pub use json::{parse_json_strict, StrictJsonError};
-pub use quantities::Quantity;
+pub use quantities::{compare_quantities, Quantity, QuantityError, QuantityOrdering};
 pub use validation::ValidationError;
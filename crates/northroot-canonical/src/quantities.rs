@@ -1,9 +1,26 @@
+use num_bigint::BigInt;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 
 use crate::validation::ValidationError;
 
 const DECIMAL_SCALE_MAX: u32 = 18;
+const DECIMAL_DIGITS_MAX: usize = 39;
+
+/// Reasons exact quantity arithmetic can fail.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum QuantityError {
+    /// The result exceeds the `Dec` bounds (scale above 18 or more than 39 mantissa digits).
+    #[error("result exceeds decimal bounds")]
+    Overflow,
+    /// The operation is not defined for these variants (`Rat` or `F64`).
+    #[error("unsupported quantity combination: {0} and {1}")]
+    UnsupportedCombination(&'static str, &'static str),
+    /// An operand is not a canonical encoding.
+    #[error("malformed quantity: {0}")]
+    ParseError(String),
+}
 
 /// Neutral numeric quantities for canonical events.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -110,6 +127,365 @@ impl Quantity {
         }
         Ok(Quantity::F64 { bits })
     }
+
+    /// Adds two quantities exactly.
+    ///
+    /// Supported combinations are `Int + Int` (yielding `Int`), `Dec + Dec`
+    /// (yielding `Dec` at the larger of the two scales), and `Int + Dec` in
+    /// either order (the `Int` is promoted to `Dec` at scale 0). The result
+    /// keeps the canonical minimal mantissa and never strips trailing zeros.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use northroot_canonical::Quantity;
+    ///
+    /// let total = Quantity::dec("999", 2)?.checked_add(&Quantity::dec("1", 2)?)?;
+    /// assert_eq!(total, Quantity::dec("1000", 2)?);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`QuantityError::UnsupportedCombination`] for `Rat` or `F64` operands
+    /// - [`QuantityError::ParseError`] for malformed encodings
+    /// - [`QuantityError::Overflow`] if a `Dec` result exceeds 39 mantissa digits
+    pub fn checked_add(&self, other: &Quantity) -> Result<Quantity, QuantityError> {
+        combine(self, other, |a, b, scale| {
+            (rescale(a.0, a.1, scale) + rescale(b.0, b.1, scale), scale)
+        })
+    }
+
+    /// Subtracts `other` from `self` exactly.
+    ///
+    /// Follows the same variant and scale rules as [`Quantity::checked_add`];
+    /// negative results are returned as negative quantities.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Quantity::checked_add`].
+    pub fn checked_sub(&self, other: &Quantity) -> Result<Quantity, QuantityError> {
+        combine(self, other, |a, b, scale| {
+            (rescale(a.0, a.1, scale) - rescale(b.0, b.1, scale), scale)
+        })
+    }
+
+    /// Multiplies two quantities exactly.
+    ///
+    /// `Int * Int` yields `Int`; any product involving a `Dec` yields a `Dec`
+    /// whose scale is the sum of the operand scales. If that sum exceeds 18,
+    /// trailing zeros are dropped from the mantissa until the scale is 18,
+    /// which leaves the value unchanged; a product that still needs more than
+    /// 18 fractional digits is an overflow rather than a rounded result.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use northroot_canonical::Quantity;
+    ///
+    /// // 1.5 * 0.25 = 0.375
+    /// let product = Quantity::dec("15", 1)?.checked_mul(&Quantity::dec("25", 2)?)?;
+    /// assert_eq!(product, Quantity::dec("375", 3)?);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - [`QuantityError::UnsupportedCombination`] for `Rat` or `F64` operands
+    /// - [`QuantityError::ParseError`] for malformed encodings
+    /// - [`QuantityError::Overflow`] if the result scale exceeds 18 or the
+    ///   mantissa exceeds 39 digits
+    pub fn checked_mul(&self, other: &Quantity) -> Result<Quantity, QuantityError> {
+        combine(self, other, |a, b, _| (a.0 * b.0, a.1 + b.1))
+    }
+
+    /// Converts `Int`, `Dec`, or `Rat` into a `Dec` at `scale`, rounding toward zero.
+    ///
+    /// Returns `None` for `F64`, malformed encodings, or a scale above 18.
+    /// Use [`Quantity::to_dec_with_truncation`] to learn whether rounding
+    /// discarded a non-zero remainder.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use northroot_canonical::Quantity;
+    ///
+    /// let third = Quantity::rat("1", "3")?;
+    /// assert_eq!(third.to_dec(4), Some(Quantity::dec("3333", 4)?));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_dec(&self, scale: u32) -> Option<Quantity> {
+        self.to_dec_with_truncation(scale).map(|(dec, _)| dec)
+    }
+
+    /// Like [`Quantity::to_dec`], also returning `true` if the value was truncated.
+    ///
+    /// The flag is `false` exactly when the conversion is value-preserving,
+    /// i.e. no non-zero remainder was discarded. The returned `Dec` need not
+    /// be `==` to `self`: `dec("150", 2)` converts to `dec("15", 1)` without
+    /// truncation.
+    pub fn to_dec_with_truncation(&self, scale: u32) -> Option<(Quantity, bool)> {
+        if scale > DECIMAL_SCALE_MAX {
+            return None;
+        }
+        let (numerator, denominator) = match self {
+            Quantity::Int { v } => (parse_integer(v)?, BigInt::from(1u8)),
+            Quantity::Dec { m, s } if *s <= DECIMAL_SCALE_MAX => {
+                (parse_integer(m)?, BigInt::from(10u8).pow(*s))
+            }
+            Quantity::Rat { n, d } => (parse_integer(n)?, parse_denominator(d)?),
+            _ => return None,
+        };
+
+        // BigInt division truncates toward zero.
+        let scaled = numerator * BigInt::from(10u8).pow(scale);
+        let truncated = &scaled % &denominator != BigInt::from(0u8);
+        let m = scaled / denominator;
+        Some((
+            Quantity::Dec {
+                m: m.to_string(),
+                s: scale,
+            },
+            truncated,
+        ))
+    }
+
+    /// Compares two quantities exactly; see [`compare_quantities`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use northroot_canonical::{Quantity, QuantityOrdering};
+    ///
+    /// let a = Quantity::dec("150", 2)?;
+    /// assert_eq!(a.compare(&Quantity::dec("15", 1)?), QuantityOrdering::Equal);
+    /// assert_eq!(a.compare(&Quantity::int("1")?), QuantityOrdering::Incomparable);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn compare(&self, other: &Quantity) -> QuantityOrdering {
+        compare_quantities(self, other).into()
+    }
+
+    /// Renders the quantity for human-readable output.
+    ///
+    /// `Int` prints as its integer, `Dec` places the decimal point using the
+    /// scale (keeping trailing zeros, so `dec("5000", 2)` prints `50.00`),
+    /// `Rat` prints as `n/d`, and `F64` prints the decoded float. The encoded
+    /// strings are not re-validated; malformed `F64` bits print as stored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use northroot_canonical::Quantity;
+    ///
+    /// assert_eq!(Quantity::int("500")?.to_display_string(), "500");
+    /// assert_eq!(Quantity::dec("-5", 3)?.to_display_string(), "-0.005");
+    /// assert_eq!(Quantity::rat("1", "3")?.to_display_string(), "1/3");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_display_string(&self) -> String {
+        match self {
+            Quantity::Int { v } => v.clone(),
+            Quantity::Dec { m, s } => {
+                let (sign, digits) = match m.strip_prefix('-') {
+                    Some(digits) => ("-", digits),
+                    None => ("", m.as_str()),
+                };
+                let scale = *s as usize;
+                if scale == 0 {
+                    return m.clone();
+                }
+                // Pad by hand: a deserialized scale can exceed what a
+                // format width accepts.
+                let digit_count = digits.chars().count();
+                let mut padded = "0".repeat((scale + 1).saturating_sub(digit_count));
+                padded.push_str(digits);
+                let point = padded
+                    .char_indices()
+                    .rev()
+                    .nth(scale - 1)
+                    .map_or(0, |(index, _)| index);
+                let (whole, fraction) = padded.split_at(point);
+                format!("{}{}.{}", sign, whole, fraction)
+            }
+            Quantity::Rat { n, d } => format!("{}/{}", n, d),
+            Quantity::F64 { bits } => match decode_f64(bits) {
+                Some(value) => value.to_string(),
+                None => bits.clone(),
+            },
+        }
+    }
+}
+
+/// Result of [`Quantity::compare`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuantityOrdering {
+    /// `self` is less than `other`.
+    Less,
+    /// The quantities have equal values.
+    Equal,
+    /// `self` is greater than `other`.
+    Greater,
+    /// No ordering is defined: mixed variants, malformed encodings, or NaN.
+    Incomparable,
+}
+
+impl From<Option<Ordering>> for QuantityOrdering {
+    fn from(ordering: Option<Ordering>) -> Self {
+        match ordering {
+            Some(Ordering::Less) => Self::Less,
+            Some(Ordering::Equal) => Self::Equal,
+            Some(Ordering::Greater) => Self::Greater,
+            None => Self::Incomparable,
+        }
+    }
+}
+
+/// Applies an exact operation to `Int`/`Dec` operands as `(mantissa, scale)` pairs.
+///
+/// `op` receives both operands and the larger operand scale, and returns the
+/// result mantissa and scale. `Int` operands yield an `Int` result.
+fn combine(
+    a: &Quantity,
+    b: &Quantity,
+    op: impl Fn((BigInt, u32), (BigInt, u32), u32) -> (BigInt, u32),
+) -> Result<Quantity, QuantityError> {
+    if [a, b]
+        .iter()
+        .any(|q| matches!(q, Quantity::Rat { .. } | Quantity::F64 { .. }))
+    {
+        return Err(QuantityError::UnsupportedCombination(
+            variant_name(a),
+            variant_name(b),
+        ));
+    }
+    let (a_value, b_value) = (as_decimal(a)?, as_decimal(b)?);
+    let scale = a_value.1.max(b_value.1);
+    let (mut m, mut s) = op(a_value, b_value, scale);
+    if let (Quantity::Int { .. }, Quantity::Int { .. }) = (a, b) {
+        return Ok(Quantity::Int { v: m.to_string() });
+    }
+
+    // A product's scale can exceed the bound while its value still fits:
+    // drop trailing zeros, only as far as needed, before giving up.
+    let ten = BigInt::from(10u8);
+    while s > DECIMAL_SCALE_MAX && &m % &ten == BigInt::from(0u8) {
+        m /= &ten;
+        s -= 1;
+    }
+
+    let m = m.to_string();
+    if s > DECIMAL_SCALE_MAX || m.trim_start_matches('-').len() > DECIMAL_DIGITS_MAX {
+        return Err(QuantityError::Overflow);
+    }
+    Ok(Quantity::Dec { m, s })
+}
+
+/// Returns the mantissa and scale of an `Int` (scale 0) or `Dec` quantity.
+fn as_decimal(quantity: &Quantity) -> Result<(BigInt, u32), QuantityError> {
+    let parsed = match quantity {
+        Quantity::Int { v } => parse_integer(v).map(|v| (v, 0)),
+        Quantity::Dec { m, s } if *s <= DECIMAL_SCALE_MAX => parse_integer(m).map(|m| (m, *s)),
+        _ => None,
+    };
+    parsed.ok_or_else(|| QuantityError::ParseError(format!("{:?}", quantity)))
+}
+
+/// Returns the canonical `t` tag for a quantity variant.
+fn variant_name(quantity: &Quantity) -> &'static str {
+    match quantity {
+        Quantity::Dec { .. } => "dec",
+        Quantity::Int { .. } => "int",
+        Quantity::Rat { .. } => "rat",
+        Quantity::F64 { .. } => "f64",
+    }
+}
+
+/// Compares two quantities exactly, without implicit coercion between variants.
+///
+/// Only same-variant comparisons are defined: `Int` with `Int`, `Dec` with `Dec`
+/// (aligned to the larger scale), `Rat` with `Rat` (cross-multiplied), and
+/// `F64` with `F64` (IEEE-754 ordering of the decoded bits). Mixed variants,
+/// malformed encodings, and NaN comparisons return `None`.
+///
+/// # Example
+///
+/// ```rust
+/// use northroot_canonical::{compare_quantities, Quantity};
+/// use std::cmp::Ordering;
+///
+/// let a = Quantity::int("9")?;
+/// let b = Quantity::int("10")?;
+/// assert_eq!(compare_quantities(&a, &b), Some(Ordering::Less));
+///
+/// // 1.50 == 1.5 even though the scales differ.
+/// let a = Quantity::dec("150", 2)?;
+/// let b = Quantity::dec("15", 1)?;
+/// assert_eq!(compare_quantities(&a, &b), Some(Ordering::Equal));
+///
+/// // 2/3 > 3/5
+/// let a = Quantity::rat("2", "3")?;
+/// let b = Quantity::rat("3", "5")?;
+/// assert_eq!(compare_quantities(&a, &b), Some(Ordering::Greater));
+///
+/// // Mixed variants are never coerced.
+/// let a = Quantity::int("1")?;
+/// let b = Quantity::dec("1", 0)?;
+/// assert_eq!(compare_quantities(&a, &b), None);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn compare_quantities(a: &Quantity, b: &Quantity) -> Option<Ordering> {
+    match (a, b) {
+        (Quantity::Int { v: a }, Quantity::Int { v: b }) => {
+            Some(parse_integer(a)?.cmp(&parse_integer(b)?))
+        }
+        (Quantity::Dec { m: am, s: a_scale }, Quantity::Dec { m: bm, s: b_scale }) => {
+            if *a_scale > DECIMAL_SCALE_MAX || *b_scale > DECIMAL_SCALE_MAX {
+                return None;
+            }
+            let scale = (*a_scale).max(*b_scale);
+            let a = rescale(parse_integer(am)?, *a_scale, scale);
+            let b = rescale(parse_integer(bm)?, *b_scale, scale);
+            Some(a.cmp(&b))
+        }
+        (Quantity::Rat { n: an, d: ad }, Quantity::Rat { n: bn, d: bd }) => {
+            let (an, ad) = (parse_integer(an)?, parse_denominator(ad)?);
+            let (bn, bd) = (parse_integer(bn)?, parse_denominator(bd)?);
+            Some((an * bd).cmp(&(bn * ad)))
+        }
+        (Quantity::F64 { bits: a }, Quantity::F64 { bits: b }) => {
+            decode_f64(a)?.partial_cmp(&decode_f64(b)?)
+        }
+        _ => None,
+    }
+}
+
+/// Parses a minimal signed integer string.
+fn parse_integer(value: &str) -> Option<BigInt> {
+    if !is_valid_integer(value) {
+        return None;
+    }
+    value.parse().ok()
+}
+
+/// Parses a minimal positive integer string.
+fn parse_denominator(value: &str) -> Option<BigInt> {
+    if !is_valid_positive_integer(value) {
+        return None;
+    }
+    value.parse().ok()
+}
+
+/// Scales a mantissa from `from` to `to` decimal places (`to >= from`).
+fn rescale(mantissa: BigInt, from: u32, to: u32) -> BigInt {
+    mantissa * BigInt::from(10u8).pow(to - from)
+}
+
+fn decode_f64(bits: &str) -> Option<f64> {
+    if bits.len() != 16 || !bits.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f')) {
+        return None;
+    }
+    u64::from_str_radix(bits, 16).ok().map(f64::from_bits)
 }
 
 fn is_valid_integer(value: &str) -> bool {
@@ -127,3 +503,245 @@ fn is_valid_positive_integer(value: &str) -> bool {
     let re = Regex::new(r"^[1-9][0-9]*$").expect("invalid regex");
     re.is_match(value)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compare_handles_negative_and_large_values() {
+        let a = Quantity::int("-123456789012345678901234567890").unwrap();
+        let b = Quantity::int("-2").unwrap();
+        assert_eq!(compare_quantities(&a, &b), Some(Ordering::Less));
+
+        let a = Quantity::dec("-1", 18).unwrap();
+        let b = Quantity::dec("0", 0).unwrap();
+        assert_eq!(compare_quantities(&a, &b), Some(Ordering::Less));
+    }
+
+    #[test]
+    fn compare_rejects_malformed_encodings() {
+        let valid = Quantity::int("1").unwrap();
+        let malformed = Quantity::Int {
+            v: "01".to_string(),
+        };
+        assert_eq!(compare_quantities(&valid, &malformed), None);
+
+        let zero_denominator = Quantity::Rat {
+            n: "1".to_string(),
+            d: "0".to_string(),
+        };
+        let rat = Quantity::rat("1", "2").unwrap();
+        assert_eq!(compare_quantities(&rat, &zero_denominator), None);
+
+        // An out-of-range scale is rejected before rescaling.
+        let dec = Quantity::dec("1", 0).unwrap();
+        let huge_scale = Quantity::Dec {
+            m: "1".to_string(),
+            s: 4_000_000_000,
+        };
+        assert_eq!(compare_quantities(&dec, &huge_scale), None);
+        assert_eq!(dec.compare(&huge_scale), QuantityOrdering::Incomparable);
+    }
+
+    #[test]
+    fn checked_add_normalizes_to_larger_scale() {
+        let a = Quantity::dec("999", 2).unwrap();
+        let b = Quantity::dec("1", 2).unwrap();
+        assert_eq!(a.checked_add(&b), Ok(Quantity::dec("1000", 2).unwrap()));
+
+        let a = Quantity::dec("15", 1).unwrap();
+        let b = Quantity::dec("25", 3).unwrap();
+        assert_eq!(a.checked_add(&b), Ok(Quantity::dec("1525", 3).unwrap()));
+    }
+
+    #[test]
+    fn checked_add_promotes_int_to_dec() {
+        let a = Quantity::int("2").unwrap();
+        let b = Quantity::dec("50", 2).unwrap();
+        assert_eq!(a.checked_add(&b), Ok(Quantity::dec("250", 2).unwrap()));
+        assert_eq!(b.checked_add(&a), Ok(Quantity::dec("250", 2).unwrap()));
+
+        let c = Quantity::int("99999999999999999999999999999999").unwrap();
+        assert_eq!(
+            c.checked_add(&Quantity::int("1").unwrap()),
+            Ok(Quantity::int("100000000000000000000000000000000").unwrap())
+        );
+    }
+
+    #[test]
+    fn checked_sub_keeps_canonical_form() {
+        let a = Quantity::dec("100", 2).unwrap();
+        let b = Quantity::dec("100", 2).unwrap();
+        assert_eq!(a.checked_sub(&b), Ok(Quantity::dec("0", 2).unwrap()));
+
+        let a = Quantity::int("1").unwrap();
+        let b = Quantity::int("3").unwrap();
+        assert_eq!(a.checked_sub(&b), Ok(Quantity::int("-2").unwrap()));
+    }
+
+    #[test]
+    fn checked_add_rejects_rat_f64_and_malformed() {
+        let int = Quantity::int("1").unwrap();
+        let rat = Quantity::rat("1", "3").unwrap();
+        let f64 = Quantity::f64("3ff0000000000000").unwrap();
+        assert_eq!(
+            int.checked_add(&rat),
+            Err(QuantityError::UnsupportedCombination("int", "rat"))
+        );
+        assert_eq!(
+            rat.checked_add(&rat),
+            Err(QuantityError::UnsupportedCombination("rat", "rat"))
+        );
+        assert_eq!(
+            f64.checked_sub(&int),
+            Err(QuantityError::UnsupportedCombination("f64", "int"))
+        );
+
+        let malformed = Quantity::Dec {
+            m: "-0".to_string(),
+            s: 1,
+        };
+        assert!(matches!(
+            int.checked_add(&malformed),
+            Err(QuantityError::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn checked_mul_adds_scales() {
+        let a = Quantity::dec("-15", 1).unwrap();
+        let b = Quantity::int("4").unwrap();
+        assert_eq!(a.checked_mul(&b), Ok(Quantity::dec("-60", 1).unwrap()));
+
+        let a = Quantity::int("12").unwrap();
+        assert_eq!(a.checked_mul(&b), Ok(Quantity::int("48").unwrap()));
+    }
+
+    #[test]
+    fn checked_arithmetic_reports_overflow() {
+        let a = Quantity::dec("1", 10).unwrap();
+        let b = Quantity::dec("1", 9).unwrap();
+        assert_eq!(a.checked_mul(&b), Err(QuantityError::Overflow));
+
+        let max = Quantity::dec("9".repeat(39), 0).unwrap();
+        let one = Quantity::dec("1", 0).unwrap();
+        assert_eq!(max.checked_add(&one), Err(QuantityError::Overflow));
+        assert!(max.checked_sub(&one).is_ok());
+    }
+
+    #[test]
+    fn to_dec_truncates_toward_zero() {
+        let third = Quantity::rat("1", "3").unwrap();
+        assert_eq!(
+            third.to_dec_with_truncation(4),
+            Some((Quantity::dec("3333", 4).unwrap(), true))
+        );
+
+        let negative = Quantity::rat("-2", "3").unwrap();
+        assert_eq!(negative.to_dec(2), Some(Quantity::dec("-66", 2).unwrap()));
+
+        let tiny = Quantity::rat("-1", "3").unwrap();
+        assert_eq!(tiny.to_dec(0), Some(Quantity::dec("0", 0).unwrap()));
+    }
+
+    #[test]
+    fn to_dec_reports_exact_conversions() {
+        let half = Quantity::rat("1", "2").unwrap();
+        assert_eq!(
+            half.to_dec_with_truncation(1),
+            Some((Quantity::dec("5", 1).unwrap(), false))
+        );
+        assert_eq!(
+            Quantity::int("-7").unwrap().to_dec_with_truncation(2),
+            Some((Quantity::dec("-700", 2).unwrap(), false))
+        );
+        assert_eq!(
+            Quantity::dec("12345", 3).unwrap().to_dec_with_truncation(1),
+            Some((Quantity::dec("123", 1).unwrap(), true))
+        );
+    }
+
+    #[test]
+    fn to_dec_rejects_f64_and_out_of_range_scale() {
+        assert_eq!(Quantity::f64("3ff0000000000000").unwrap().to_dec(2), None);
+        assert_eq!(Quantity::int("1").unwrap().to_dec(19), None);
+    }
+
+    #[test]
+    fn compare_f64_uses_ieee_ordering() {
+        let one = Quantity::f64(format!("{:016x}", 1.0f64.to_bits())).unwrap();
+        let two = Quantity::f64(format!("{:016x}", 2.0f64.to_bits())).unwrap();
+        let nan = Quantity::f64(format!("{:016x}", f64::NAN.to_bits())).unwrap();
+        assert_eq!(compare_quantities(&one, &two), Some(Ordering::Less));
+        assert_eq!(compare_quantities(&one, &nan), None);
+    }
+
+    #[test]
+    fn compare_covers_every_ordering() {
+        let one = Quantity::int("1").unwrap();
+        let two = Quantity::int("2").unwrap();
+        assert_eq!(one.compare(&two), QuantityOrdering::Less);
+        assert_eq!(two.compare(&one), QuantityOrdering::Greater);
+        assert_eq!(
+            Quantity::rat("2", "4")
+                .unwrap()
+                .compare(&Quantity::rat("1", "2").unwrap()),
+            QuantityOrdering::Equal
+        );
+        assert_eq!(
+            one.compare(&Quantity::dec("1", 0).unwrap()),
+            QuantityOrdering::Incomparable
+        );
+        let nan = Quantity::F64 {
+            bits: format!("{:016x}", f64::NAN.to_bits()),
+        };
+        assert_eq!(nan.compare(&nan), QuantityOrdering::Incomparable);
+    }
+
+    #[test]
+    fn display_string_places_decimal_point() {
+        let show = |q: Quantity| q.to_display_string();
+        assert_eq!(show(Quantity::int("-42").unwrap()), "-42");
+        assert_eq!(show(Quantity::dec("5000", 2).unwrap()), "50.00");
+        assert_eq!(show(Quantity::dec("7", 0).unwrap()), "7");
+        // Scale larger than the mantissa length needs leading zeros.
+        assert_eq!(show(Quantity::dec("5", 3).unwrap()), "0.005");
+        assert_eq!(show(Quantity::dec("-123", 5).unwrap()), "-0.00123");
+        assert_eq!(show(Quantity::dec("0", 2).unwrap()), "0.00");
+        assert_eq!(show(Quantity::rat("-2", "3").unwrap()), "-2/3");
+        // Deserialized values are not range-checked; huge scales still render.
+        let huge = show(Quantity::Dec {
+            m: "1".to_string(),
+            s: 70000,
+        });
+        assert_eq!(huge.len(), 70002);
+        assert!(huge.starts_with("0.000") && huge.ends_with("001"));
+        assert_eq!(
+            show(Quantity::f64(format!("{:016x}", 1.5f64.to_bits())).unwrap()),
+            "1.5"
+        );
+    }
+
+    #[test]
+    fn mul_keeps_high_scale_products_bounded() {
+        // 0.1000000000 * 0.1000000000 = 0.01, stored at scale 18 rather than 20.
+        let a = Quantity::dec("1000000000", 10).unwrap();
+        let product = a.checked_mul(&a).unwrap();
+        assert_eq!(product, Quantity::dec("10000000000000000", 18).unwrap());
+        assert_eq!(
+            compare_quantities(&product, &Quantity::dec("1", 2).unwrap()),
+            Some(Ordering::Equal)
+        );
+
+        // 1e-18 * 1e-18 has no representation at scale 18.
+        let tiny = Quantity::dec("1", 18).unwrap();
+        assert_eq!(tiny.checked_mul(&tiny), Err(QuantityError::Overflow));
+
+        // Below the bound, trailing zeros are kept as before.
+        let product = Quantity::dec("10", 1)
+            .unwrap()
+            .checked_mul(&Quantity::dec("10", 1).unwrap());
+        assert_eq!(product, Ok(Quantity::dec("100", 2).unwrap()));
+    }
+}
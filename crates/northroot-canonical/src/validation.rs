@@ -11,6 +11,14 @@ pub enum ValidationError {
         /// Offending value.
         value: String,
     },
+    /// When a digest is not well-formed for its algorithm.
+    #[error("digest ('{value}') is invalid: {reason}")]
+    InvalidDigest {
+        /// Offending value.
+        value: String,
+        /// What is wrong with it.
+        reason: String,
+    },
     /// When a numeric quantity exceeds its bounds.
     #[error("{field} ({value}) is out of bounds")]
     OutOfBounds {
@@ -1,8 +1,8 @@
 use std::collections::BTreeMap;
 
 use northroot_canonical::{
-    canonicalizer::Canonicalizer, compute_blob_digest, ContentRef, Digest, DigestAlg,
-    HygieneReport, HygieneStatus, HygieneWarning, ProfileId, Quantity,
+    canonicalizer::Canonicalizer, compute_blob_digest, CanonicalizationError, ContentRef, Digest,
+    DigestAlg, HygieneReport, HygieneStatus, HygieneWarning, ProfileId, Quantity,
 };
 use serde_json::json;
 
@@ -40,6 +40,60 @@ fn quantity_dec_serialization_is_deterministic() {
     );
 }
 
+#[test]
+fn structural_integers_canonicalize_as_json_numbers() {
+    let canonicalizer = Canonicalizer::new(ProfileId::parse("northroot-canonical-v1").unwrap());
+
+    let quantity = serde_json::to_value(Quantity::dec("12345", 2).unwrap()).unwrap();
+    assert_eq!(
+        canonicalizer.canonicalize(&quantity).unwrap().bytes,
+        br#"{"m":"12345","s":2,"t":"dec"}"#.to_vec()
+    );
+
+    let content_ref = serde_json::to_value(ContentRef {
+        digest: compute_blob_digest(b"northroot artifact bytes").unwrap(),
+        size_bytes: Some(42),
+        media_type: Some("application/json".into()),
+    })
+    .unwrap();
+    assert_eq!(
+        canonicalizer.canonicalize(&content_ref).unwrap().bytes,
+        br#"{"digest":{"alg":"sha-256","b64":"bJeloqUODbW_XyWyWOnvTXqujZKnX7R2D2j86EJG7pY"},"media_type":"application/json","size_bytes":42}"#.to_vec()
+    );
+}
+
+#[test]
+fn structural_integers_reject_string_form() {
+    let stringified_scale = json!({"t": "dec", "m": "12345", "s": "2"});
+    assert!(serde_json::from_value::<Quantity>(stringified_scale.clone()).is_err());
+
+    let stringified_size = json!({
+        "digest": {"alg": "sha-256", "b64": "bJeloqUODbW_XyWyWOnvTXqujZKnX7R2D2j86EJG7pY"},
+        "size_bytes": "42"
+    });
+    assert!(serde_json::from_value::<ContentRef>(stringified_size.clone()).is_err());
+
+    // The canonicalizer rejects the string form too, parsed or streamed.
+    let canonicalizer = Canonicalizer::new(ProfileId::parse("northroot-canonical-v1").unwrap());
+    for (value, path) in [(stringified_scale, "s"), (stringified_size, "size_bytes")] {
+        let err = canonicalizer.canonicalize(&value).unwrap_err();
+        assert!(
+            matches!(&err, CanonicalizationError::InvalidStructure(msg) if msg.contains(path)),
+            "{err}"
+        );
+        let nested = json!({"meter": value});
+        let (err, report) = canonicalizer
+            .canonicalize_bytes(nested.to_string().as_bytes())
+            .unwrap_err();
+        assert!(matches!(err, CanonicalizationError::InvalidStructure(_)));
+        assert_eq!(report.status, HygieneStatus::Invalid);
+    }
+
+    // Only envelopes are constrained: other objects may use these keys freely.
+    let unrelated = json!({"t": "label", "s": "2", "size_bytes": "42"});
+    assert!(canonicalizer.canonicalize(&unrelated).is_ok());
+}
+
 #[test]
 fn hygiene_report_matches_expected_shape() {
     let report = HygieneReport {
@@ -116,7 +170,43 @@ fn canonicalizer_validates_object_structure() {
 
     // Note: Duplicate key detection is not performed here because
     // serde_json::Value::Object cannot have duplicates by design.
-    // Duplicate detection should happen at the JSON parsing layer.
+    // canonicalize_bytes detects them in the raw input.
+}
+
+#[test]
+fn canonicalize_bytes_rejects_duplicate_keys() {
+    let canonicalizer = Canonicalizer::new(ProfileId::parse("northroot-canonical-v1").unwrap());
+
+    for (raw, path) in [
+        (r#"{"a":1,"b":2,"a":3}"#, "a"),
+        (
+            r#"{"outer":{"inner":[{"id":1,"id":1}]}}"#,
+            "outer.inner.[0].id",
+        ),
+    ] {
+        let (err, report) = canonicalizer
+            .canonicalize_bytes(raw.as_bytes())
+            .unwrap_err();
+        assert!(
+            matches!(&err, CanonicalizationError::DuplicateKey(at) if at == path),
+            "{}: {:?}",
+            raw,
+            err
+        );
+        assert_eq!(report.status, HygieneStatus::Invalid);
+        assert_eq!(report.warnings, [HygieneWarning::new("DuplicateKeys")]);
+        assert_eq!(report.metrics.get("duplicate_keys"), Some(&1));
+    }
+
+    // The same key in sibling objects is not a duplicate.
+    let raw = br#"{"right":{"id":2},"left":{"id":1}}"#;
+    let result = canonicalizer.canonicalize_bytes(raw).unwrap();
+    let value: serde_json::Value = serde_json::from_slice(raw).unwrap();
+    assert_eq!(
+        result.bytes,
+        canonicalizer.canonicalize(&value).unwrap().bytes
+    );
+    assert_eq!(result.report.status, HygieneStatus::Ok);
 }
 
 #[test]
@@ -296,3 +386,68 @@ fn canonicalizer_can_canonicalize_quantity_with_scale() {
     assert!(canonical_str.contains(r#""m":"12345""#));
     assert!(canonical_str.contains(r#""s":2"#)); // Scale as integer
 }
+
+#[test]
+fn streaming_canonicalization_matches_golden_fixtures() {
+    let canonicalizer = Canonicalizer::new(ProfileId::parse("northroot-canonical-v1").unwrap());
+    let fixtures =
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../fixtures/canonical");
+    let mut checked = 0;
+    for entry in std::fs::read_dir(&fixtures).unwrap() {
+        let input_path = entry.unwrap().path();
+        let Some(name) = input_path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_suffix("_input.json"))
+        else {
+            continue;
+        };
+        let input = std::fs::read(&input_path).unwrap();
+        let golden = hex::decode(
+            std::fs::read_to_string(fixtures.join(format!("{}_canonical.hex", name)))
+                .unwrap()
+                .trim(),
+        )
+        .unwrap();
+
+        let mut streamed = Vec::new();
+        let report = canonicalizer
+            .canonicalize_reader(input.as_slice(), &mut streamed)
+            .unwrap();
+        let dom = canonicalizer
+            .canonicalize(&serde_json::from_slice(&input).unwrap())
+            .unwrap();
+
+        assert_eq!(streamed, golden, "{}", name);
+        assert_eq!(streamed, dom.bytes, "{}", name);
+        assert_eq!(report, dom.report, "{}", name);
+        checked += 1;
+    }
+    assert_eq!(checked, 7);
+}
+
+#[test]
+fn streaming_canonicalization_rejects_what_strict_parsing_rejects() {
+    let canonicalizer = Canonicalizer::new(ProfileId::parse("northroot-canonical-v1").unwrap());
+    let stream = |input: &str| canonicalizer.canonicalize_reader(input.as_bytes(), Vec::new());
+
+    assert!(matches!(
+        stream(r#"{"outer":{"a":1,"a":2}}"#),
+        Err(CanonicalizationError::DuplicateKey(path)) if path == "outer.a"
+    ));
+    assert!(matches!(
+        stream(r#"{"a":1} trailing"#),
+        Err(CanonicalizationError::InvalidStructure(_))
+    ));
+    assert!(matches!(
+        stream(r#"[1e400]"#),
+        Err(CanonicalizationError::InvalidStructure(_))
+    ));
+
+    let nested = serde_json::json!({"list": [1.5, -2, 1e21, "\u{1F600}\n", null, true, {}, []]});
+    let mut streamed = Vec::new();
+    canonicalizer
+        .canonicalize_reader(nested.to_string().as_bytes(), &mut streamed)
+        .unwrap();
+    assert_eq!(streamed, canonicalizer.canonicalize(&nested).unwrap().bytes);
+}
@@ -0,0 +1,121 @@
+//! Property tests for exact quantity comparison.
+
+use std::cmp::Ordering;
+
+use northroot_canonical::{compare_quantities, Quantity};
+use num_bigint::BigInt;
+use proptest::prelude::*;
+
+/// Signed integers both small (to hit equal values often) and far beyond
+/// the i128 range (to catch fixed-width overflow).
+fn integer() -> impl Strategy<Value = BigInt> {
+    prop_oneof![
+        (-20i64..=20).prop_map(BigInt::from),
+        (any::<i128>(), 0u32..40).prop_map(|(v, k)| BigInt::from(v) * BigInt::from(10u8).pow(k)),
+    ]
+}
+
+fn positive_integer() -> impl Strategy<Value = BigInt> {
+    integer().prop_map(|v| {
+        if v == BigInt::from(0) {
+            BigInt::from(1)
+        } else {
+            v.magnitude().clone().into()
+        }
+    })
+}
+
+fn int_quantity() -> impl Strategy<Value = Quantity> {
+    integer().prop_map(|v| Quantity::int(v.to_string()).unwrap())
+}
+
+fn dec_quantity() -> impl Strategy<Value = Quantity> {
+    (integer(), 0u32..=18).prop_map(|(m, s)| Quantity::dec(m.to_string(), s).unwrap())
+}
+
+fn rat_quantity() -> impl Strategy<Value = Quantity> {
+    (integer(), positive_integer())
+        .prop_map(|(n, d)| Quantity::rat(n.to_string(), d.to_string()).unwrap())
+}
+
+fn gcd(a: BigInt, b: BigInt) -> BigInt {
+    let (mut a, mut b) = (a, b);
+    while b != BigInt::from(0) {
+        let r = &a % &b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+/// Reduces a quantity to a unique representation of its value, computed
+/// independently of `compare_quantities`.
+fn normal_form(quantity: &Quantity) -> (BigInt, BigInt) {
+    match quantity {
+        Quantity::Int { v } => (v.parse().unwrap(), BigInt::from(1)),
+        Quantity::Dec { m, s } => {
+            let ten = BigInt::from(10u8);
+            let (mut m, mut s): (BigInt, u32) = (m.parse().unwrap(), *s);
+            while s > 0 && &m % &ten == BigInt::from(0) {
+                m /= &ten;
+                s -= 1;
+            }
+            (m, ten.pow(s))
+        }
+        Quantity::Rat { n, d } => {
+            let (n, d): (BigInt, BigInt) = (n.parse().unwrap(), d.parse().unwrap());
+            let g = gcd(n.magnitude().clone().into(), d.clone());
+            (n / &g, d / g)
+        }
+        Quantity::F64 { .. } => unreachable!("not generated"),
+    }
+}
+
+fn check_order(a: &Quantity, b: &Quantity, c: &Quantity) -> Result<(), TestCaseError> {
+    let ab = compare_quantities(a, b).unwrap();
+    let ba = compare_quantities(b, a).unwrap();
+    let bc = compare_quantities(b, c).unwrap();
+    let ac = compare_quantities(a, c).unwrap();
+
+    // Antisymmetry.
+    prop_assert_eq!(ab, ba.reverse());
+    // a <= b && b <= a implies equal values.
+    prop_assert_eq!(ab == Ordering::Equal, normal_form(a) == normal_form(b));
+    // Transitivity.
+    if ab != Ordering::Greater && bc != Ordering::Greater {
+        prop_assert_ne!(ac, Ordering::Greater);
+    }
+    if ab != Ordering::Less && bc != Ordering::Less {
+        prop_assert_ne!(ac, Ordering::Less);
+    }
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn int_comparison_is_a_total_order(
+        a in int_quantity(),
+        b in int_quantity(),
+        c in int_quantity(),
+    ) {
+        check_order(&a, &b, &c)?;
+    }
+
+    #[test]
+    fn dec_comparison_is_a_total_order(
+        a in dec_quantity(),
+        b in dec_quantity(),
+        c in dec_quantity(),
+    ) {
+        check_order(&a, &b, &c)?;
+    }
+
+    #[test]
+    fn rat_comparison_is_a_total_order(
+        a in rat_quantity(),
+        b in rat_quantity(),
+        c in rat_quantity(),
+    ) {
+        check_order(&a, &b, &c)?;
+    }
+}
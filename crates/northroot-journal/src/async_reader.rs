@@ -0,0 +1,169 @@
+//! Async journal reader implementation.
+
+use crate::errors::JournalError;
+use crate::event::EventJson;
+use crate::footer::{FooterState, JournalFooter};
+use crate::frame::{FrameKind, JournalHeader, RecordFrame, FRAME_CRC_SIZE};
+use crate::reader::{
+    check_footer, corrupt_frame, crc_matches, parse_event_frame, parse_frame_header, read_failure,
+    ReadMode,
+};
+use std::io::SeekFrom;
+use std::path::Path;
+use tokio::fs::File;
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+/// Async journal reader for use inside a Tokio runtime.
+///
+/// Reads go through `tokio::fs` and decode frames with the same logic as
+/// [`JournalReader`](crate::JournalReader), so both readers return the same
+/// events and errors for the same file. [`ReadMode::Repair`] is treated like
+/// [`ReadMode::Strict`].
+///
+/// Requires the `tokio` cargo feature.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use northroot_journal::{AsyncJournalReader, ReadMode};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut reader = AsyncJournalReader::open("events.nrj", ReadMode::Strict).await?;
+/// while let Some(event) = reader.read_event().await? {
+///     println!("{}", event);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct AsyncJournalReader {
+    file: File,
+    mode: ReadMode,
+    position: u64,
+    frame_crc: bool,
+    footer_state: FooterState,
+    last_footer: Option<JournalFooter>,
+    complete: bool,
+    payload: Vec<u8>,
+}
+
+impl AsyncJournalReader {
+    /// Opens a journal file for reading.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JournalError`] under the same conditions as
+    /// [`JournalReader::open`](crate::JournalReader::open).
+    pub async fn open<P: AsRef<Path>>(path: P, mode: ReadMode) -> Result<Self, JournalError> {
+        let path = path.as_ref();
+        let mut file = File::open(path)
+            .await
+            .map_err(|e| JournalError::open(path, e))?;
+        let mut header_bytes = [0u8; JournalHeader::HEADER_SIZE];
+        file.read_exact(&mut header_bytes).await?;
+        let header = JournalHeader::from_bytes(&header_bytes)?;
+
+        Ok(Self {
+            file,
+            mode,
+            position: JournalHeader::HEADER_SIZE as u64,
+            frame_crc: header.has_frame_crc(),
+            footer_state: FooterState::new(),
+            last_footer: None,
+            complete: false,
+            payload: Vec::new(),
+        })
+    }
+
+    /// Returns the current read position in the file.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns the most recent footer read so far, if any.
+    ///
+    /// See [`JournalReader::last_footer`](crate::JournalReader::last_footer).
+    pub fn last_footer(&self) -> Option<&JournalFooter> {
+        self.last_footer.as_ref()
+    }
+
+    /// Returns `true` if the most recently read frame was a matching footer.
+    ///
+    /// See [`JournalReader::is_complete`](crate::JournalReader::is_complete).
+    pub fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    /// Reads the next event from the journal.
+    ///
+    /// Returns `Ok(None)` when end-of-file is reached (or truncation in
+    /// permissive mode). Footer frames are checked and unknown frame kinds
+    /// are skipped, as in [`JournalReader::read_event`](crate::JournalReader::read_event).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JournalError`] under the same conditions as
+    /// [`JournalReader::read_event`](crate::JournalReader::read_event) in
+    /// strict or permissive mode.
+    pub async fn read_event(&mut self) -> Result<Option<EventJson>, JournalError> {
+        loop {
+            let offset = self.position;
+            match self.read_frame().await? {
+                None => return Ok(None),
+                Some(kind) if kind.is_event() => {
+                    let json = parse_event_frame(kind, &self.payload)?;
+                    self.footer_state.record(&json);
+                    self.complete = false;
+                    return Ok(Some(json));
+                }
+                Some(FrameKind::Footer) => {
+                    let footer = check_footer(&self.footer_state, &self.payload, offset)?;
+                    self.last_footer = Some(footer);
+                    self.complete = true;
+                }
+                Some(_) => {
+                    // Skip unknown frame kinds
+                    continue;
+                }
+            }
+        }
+    }
+
+    /// Reads the next frame payload into `self.payload` and returns its kind.
+    async fn read_frame(&mut self) -> Result<Option<FrameKind>, JournalError> {
+        // As in the sync reader, advance only once the whole frame is read.
+        let frame_start = self.position;
+        let mut position = frame_start;
+        self.file.seek(SeekFrom::Start(position)).await?;
+        if position >= self.file.metadata().await?.len() {
+            return Ok(None);
+        }
+
+        let mut frame_header_bytes = [0u8; RecordFrame::FRAME_HEADER_SIZE];
+        if let Err(e) = self.file.read_exact(&mut frame_header_bytes).await {
+            return read_failure(e, self.mode, position);
+        }
+        let frame = parse_frame_header(&frame_header_bytes, position)?;
+        position += RecordFrame::FRAME_HEADER_SIZE as u64;
+
+        self.payload.clear();
+        self.payload.resize(frame.len as usize, 0);
+        if let Err(e) = self.file.read_exact(&mut self.payload).await {
+            return read_failure(e, self.mode, position);
+        }
+        position += frame.len as u64;
+
+        if self.frame_crc {
+            let mut crc_bytes = [0u8; FRAME_CRC_SIZE];
+            if let Err(e) = self.file.read_exact(&mut crc_bytes).await {
+                return read_failure(e, self.mode, position);
+            }
+            if !crc_matches(&self.payload, crc_bytes) {
+                return corrupt_frame(self.mode, frame_start);
+            }
+            position += FRAME_CRC_SIZE as u64;
+        }
+
+        self.position = position;
+        Ok(Some(frame.kind))
+    }
+}
@@ -0,0 +1,220 @@
+//! Async journal writer implementation.
+
+use crate::compression;
+use crate::errors::JournalError;
+use crate::event::EventJson;
+use crate::footer::FooterState;
+use crate::frame::{FrameKind, JournalHeader};
+use crate::index::JournalIndex;
+use crate::writer::{
+    check_compress_flag, encode_event, encode_frame, existing_state, footer_after, new_header,
+    WriteOptions,
+};
+use std::io::{self, SeekFrom};
+use std::path::{Path, PathBuf};
+use tokio::fs::{File, OpenOptions};
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+
+/// Async journal writer for use inside a Tokio runtime.
+///
+/// Writes go through `tokio::fs`, so appending never blocks the calling task
+/// on file I/O. Frames are byte-for-byte identical to those written by
+/// [`JournalWriter`](crate::JournalWriter) with the same [`WriteOptions`],
+/// and every option is honoured. Scanning an existing journal for its footer
+/// or index state, and saving the index, run on Tokio's blocking pool.
+///
+/// Requires the `tokio` cargo feature.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use northroot_journal::{AsyncJournalWriter, WriteOptions};
+/// use serde_json::json;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut writer = AsyncJournalWriter::open("events.nrj", WriteOptions::default()).await?;
+/// writer.append_event(&json!({"event_type": "test"})).await?;
+/// writer.finish().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct AsyncJournalWriter {
+    file: File,
+    path: PathBuf,
+    sync: bool,
+    frame_crc: bool,
+    footer: Option<FooterState>,
+    index: Option<JournalIndex>,
+    compress: Option<usize>,
+}
+
+impl AsyncJournalWriter {
+    /// Opens or creates a journal file for writing.
+    ///
+    /// Behaves like [`JournalWriter::open`](crate::JournalWriter::open).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JournalError`] under the same conditions as
+    /// [`JournalWriter::open`](crate::JournalWriter::open).
+    pub async fn open<P: AsRef<Path>>(
+        path: P,
+        options: WriteOptions,
+    ) -> Result<Self, JournalError> {
+        let path = path.as_ref();
+        #[cfg(not(feature = "compression"))]
+        if options.compress.is_some() {
+            return Err(compression::unavailable());
+        }
+        let file = OpenOptions::new()
+            .create(options.create)
+            .write(true)
+            .read(true)
+            .open(path)
+            .await
+            .map_err(|e| JournalError::open(path, e))?;
+
+        let mut writer = Self {
+            file,
+            path: path.to_path_buf(),
+            sync: options.sync,
+            frame_crc: options.frame_crc,
+            footer: options.write_footer.then(FooterState::new),
+            index: options.index.then(JournalIndex::new),
+            compress: options.compress,
+        };
+
+        let len = writer.file.metadata().await?.len();
+        if len == 0 {
+            writer.write_header().await?;
+        } else if len < JournalHeader::HEADER_SIZE as u64 {
+            return Err(JournalError::FileNotEmpty);
+        } else {
+            let mut header_bytes = [0u8; JournalHeader::HEADER_SIZE];
+            writer.file.seek(SeekFrom::Start(0)).await?;
+            writer.file.read_exact(&mut header_bytes).await?;
+            let header = JournalHeader::from_bytes(&header_bytes)?;
+            check_compress_flag(&header, writer.compress)?;
+            writer.frame_crc = header.has_frame_crc();
+            if options.append {
+                let (footer, index) = (writer.footer.is_some(), writer.index.is_some());
+                if footer || index {
+                    let state_path = writer.path.clone();
+                    (writer.footer, writer.index) =
+                        blocking(move || existing_state(&state_path, footer, index)).await?;
+                }
+                writer.file.seek(SeekFrom::End(0)).await?;
+            } else {
+                let header_size = JournalHeader::HEADER_SIZE as u64;
+                writer.file.set_len(header_size).await?;
+                writer.file.seek(SeekFrom::Start(header_size)).await?;
+            }
+        }
+
+        Ok(writer)
+    }
+
+    async fn write_header(&mut self) -> Result<(), JournalError> {
+        let header = new_header(self.frame_crc, self.compress.is_some());
+        self.file.write_all(&header.to_bytes()).await?;
+        self.flush().await
+    }
+
+    /// Appends an event JSON payload to the journal.
+    ///
+    /// See [`JournalWriter::append_event`](crate::JournalWriter::append_event).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JournalError`] under the same conditions as
+    /// [`JournalWriter::append_event`](crate::JournalWriter::append_event).
+    pub async fn append_event(&mut self, event: &EventJson) -> Result<(), JournalError> {
+        let json_bytes = serde_json::to_vec(event)?;
+        let footer = footer_after(self.footer.as_ref(), event)?;
+        let (kind, payload) = encode_event(self.compress, json_bytes)?;
+        let offset = self.write_frame(kind, &payload).await?;
+        self.footer = footer.or(self.footer.take());
+        if let Some(index) = self.index.as_mut() {
+            index.insert(event, offset);
+        }
+        Ok(())
+    }
+
+    /// Appends a raw frame with the given kind and payload.
+    ///
+    /// See [`JournalWriter::append_raw`](crate::JournalWriter::append_raw).
+    pub async fn append_raw(
+        &mut self,
+        kind: FrameKind,
+        payload: &[u8],
+    ) -> Result<(), JournalError> {
+        let event: Option<EventJson> =
+            if kind.is_event() && (self.footer.is_some() || self.index.is_some()) {
+                Some(serde_json::from_slice(&compression::event_payload(
+                    kind, payload,
+                )?)?)
+            } else {
+                None
+            };
+        let footer = match &event {
+            Some(event) => footer_after(self.footer.as_ref(), event)?,
+            None => None,
+        };
+        let offset = self.write_frame(kind, payload).await?;
+        self.footer = footer.or(self.footer.take());
+        if let (Some(index), Some(event)) = (self.index.as_mut(), &event) {
+            index.insert(event, offset);
+        }
+        Ok(())
+    }
+
+    /// Flushes written frames to the file, and to disk with
+    /// [`WriteOptions::sync`].
+    pub async fn flush(&mut self) -> Result<(), JournalError> {
+        self.file.flush().await?;
+        if self.sync {
+            self.file.sync_all().await?;
+        }
+        Ok(())
+    }
+
+    /// Writes a frame and returns the offset it starts at.
+    async fn write_frame(&mut self, kind: FrameKind, payload: &[u8]) -> Result<u64, JournalError> {
+        let frame_bytes = encode_frame(kind, payload, self.frame_crc)?;
+        let offset = self.file.stream_position().await?;
+        self.file.write_all(&frame_bytes).await?;
+        self.flush().await?;
+        Ok(offset)
+    }
+
+    /// Finishes writing and closes the file.
+    ///
+    /// See [`JournalWriter::finish`](crate::JournalWriter::finish).
+    pub async fn finish(mut self) -> Result<(), JournalError> {
+        if let Some(footer) = self.footer.take() {
+            let payload = footer.footer().to_bytes()?;
+            self.write_frame(FrameKind::Footer, &payload).await?;
+        }
+        self.flush().await?;
+        if let Some(mut index) = self.index.take() {
+            let path = self.path.clone();
+            blocking(move || {
+                index.stamp(&path)?;
+                index.save(&path)
+            })
+            .await?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs blocking journal work on Tokio's blocking pool.
+async fn blocking<T, F>(work: F) -> Result<T, JournalError>
+where
+    F: FnOnce() -> Result<T, JournalError> + Send + 'static,
+    T: Send + 'static,
+{
+    tokio::task::spawn_blocking(work)
+        .await
+        .map_err(|e| JournalError::Io(io::Error::other(e)))?
+}
@@ -0,0 +1,47 @@
+//! zstd payload compression for `EventJsonZstd` frames.
+
+use crate::errors::JournalError;
+use crate::frame::FrameKind;
+use std::borrow::Cow;
+
+/// zstd level used for compressed event frames.
+#[cfg(feature = "compression")]
+const ZSTD_LEVEL: i32 = 3;
+
+/// Returns the uncompressed JSON bytes of an event frame payload.
+pub(crate) fn event_payload(
+    kind: FrameKind,
+    payload: &[u8],
+) -> Result<Cow<'_, [u8]>, JournalError> {
+    match kind {
+        FrameKind::EventJsonZstd => decompress(payload).map(Cow::Owned),
+        _ => Ok(Cow::Borrowed(payload)),
+    }
+}
+
+#[cfg(feature = "compression")]
+pub(crate) fn compress(payload: &[u8]) -> Result<Vec<u8>, JournalError> {
+    zstd::bulk::compress(payload, ZSTD_LEVEL).map_err(|e| JournalError::Compression(e.to_string()))
+}
+
+#[cfg(not(feature = "compression"))]
+pub(crate) fn compress(_payload: &[u8]) -> Result<Vec<u8>, JournalError> {
+    Err(unavailable())
+}
+
+/// Decompresses a payload, refusing output larger than a frame may hold.
+#[cfg(feature = "compression")]
+fn decompress(payload: &[u8]) -> Result<Vec<u8>, JournalError> {
+    zstd::bulk::decompress(payload, crate::frame::MAX_PAYLOAD_SIZE as usize)
+        .map_err(|e| JournalError::Compression(e.to_string()))
+}
+
+#[cfg(not(feature = "compression"))]
+fn decompress(_payload: &[u8]) -> Result<Vec<u8>, JournalError> {
+    Err(unavailable())
+}
+
+#[cfg(not(feature = "compression"))]
+pub(crate) fn unavailable() -> JournalError {
+    JournalError::Compression("zstd frames require the `compression` feature".to_string())
+}
@@ -1,3 +1,4 @@
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 
 /// Errors that can occur during journal operations.
@@ -6,6 +7,18 @@ pub enum JournalError {
     /// I/O error during read or write.
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
+    /// Opening or creating the journal file failed.
+    ///
+    /// The message names only the file name so it can be shown without
+    /// leaking directory structure; `path` holds the full path as given.
+    #[error("failed to open journal {}: {source}", display_name(path))]
+    Open {
+        /// Path passed to `open`.
+        path: PathBuf,
+        /// Underlying I/O error.
+        #[source]
+        source: std::io::Error,
+    },
     /// Invalid file header (magic, version, or flags).
     #[error("invalid journal header: {0}")]
     InvalidHeader(String),
@@ -43,4 +56,43 @@ pub enum JournalError {
         /// Byte offset where truncation occurred.
         offset: u64,
     },
+    /// Frame payload does not match its CRC32C trailer.
+    #[error("corrupt frame at offset {offset}: CRC32C mismatch")]
+    FrameCorrupt {
+        /// Byte offset where the frame starts.
+        offset: u64,
+    },
+    /// Footer frame does not match the events preceding it.
+    #[error("footer mismatch at offset {offset}: {reason}")]
+    FooterMismatch {
+        /// Byte offset where the footer frame starts.
+        offset: u64,
+        /// Reason for the mismatch.
+        reason: String,
+    },
+    /// Sidecar index file is malformed or has an unsupported version.
+    #[error("invalid journal index: {0}")]
+    InvalidIndex(String),
+    /// A payload could not be compressed or decompressed, or compression
+    /// is needed but the `compression` feature is disabled.
+    #[error("compression error: {0}")]
+    Compression(String),
+    /// Computing an event's `event_id` failed.
+    #[error("event_id computation failed: {0}")]
+    EventId(#[from] northroot_canonical::EventIdError),
+}
+
+impl JournalError {
+    pub(crate) fn open(path: &Path, source: std::io::Error) -> Self {
+        JournalError::Open {
+            path: path.to_path_buf(),
+            source,
+        }
+    }
+}
+
+fn display_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "<journal file>".to_string())
 }
@@ -0,0 +1,165 @@
+use crate::errors::JournalError;
+use crate::event::EventJson;
+use northroot_canonical::{Digest, DigestAlg};
+use sha2::{Digest as Sha2Digest, Sha256};
+
+/// Footer payload size in bytes: 72 bytes.
+pub const FOOTER_SIZE: usize = 72;
+
+/// Domain separator for the footer IDs digest: `b"northroot:footer:v1\0"`.
+const FOOTER_DOMAIN_SEPARATOR: &[u8] = b"northroot:footer:v1\0";
+
+/// Summary frame written after the events it describes.
+///
+/// A footer records how many events precede it, the `event_id` of the last
+/// one (the chain tip), and a digest over every preceding `event_id` in file
+/// order. Readers compare it against the events they actually read, so a
+/// journal that ends with a matching footer was not truncated after its last
+/// intended event.
+///
+/// The payload is a fixed 72 bytes:
+///
+/// | Offset | Size | Field |
+/// | --- | --- | --- |
+/// | 0 | 8 | `event_count` (u64 little-endian) |
+/// | 8 | 32 | tip `event_id` bytes (all zeros when there are no events) |
+/// | 40 | 32 | `sha256("northroot:footer:v1\0" \|\| id_0 \|\| id_1 \|\| ...)` |
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalFooter {
+    /// Number of events preceding the footer.
+    pub event_count: u64,
+    /// `event_id` of the last preceding event, if any.
+    pub tip_event_id: Option<Digest>,
+    /// Digest over every preceding `event_id`, in file order.
+    pub ids_digest: Digest,
+}
+
+impl JournalFooter {
+    /// Serializes the footer payload to bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JournalError::InvalidJson`] if a digest does not decode to
+    /// 32 bytes.
+    pub fn to_bytes(&self) -> Result<[u8; FOOTER_SIZE], JournalError> {
+        let mut bytes = [0u8; FOOTER_SIZE];
+        bytes[0..8].copy_from_slice(&self.event_count.to_le_bytes());
+        if let Some(tip) = &self.tip_event_id {
+            bytes[8..40].copy_from_slice(&tip.to_bytes().ok_or_else(|| {
+                JournalError::InvalidJson("footer tip is not a 32-byte digest".to_string())
+            })?);
+        }
+        bytes[40..72].copy_from_slice(&self.ids_digest.to_bytes().ok_or_else(|| {
+            JournalError::InvalidJson("footer ids digest is not a 32-byte digest".to_string())
+        })?);
+        Ok(bytes)
+    }
+
+    /// Deserializes a footer payload from bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JournalError::InvalidFrame`] if the payload is not exactly
+    /// [`FOOTER_SIZE`] bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, JournalError> {
+        if bytes.len() != FOOTER_SIZE {
+            return Err(JournalError::InvalidFrame {
+                offset: 0,
+                reason: format!(
+                    "footer payload is {} bytes, expected {}",
+                    bytes.len(),
+                    FOOTER_SIZE
+                ),
+            });
+        }
+
+        let mut count = [0u8; 8];
+        count.copy_from_slice(&bytes[0..8]);
+        let event_count = u64::from_le_bytes(count);
+        let tip_event_id = (event_count > 0).then(|| encode_digest(&bytes[8..40]));
+
+        Ok(Self {
+            event_count,
+            tip_event_id,
+            ids_digest: encode_digest(&bytes[40..72]),
+        })
+    }
+}
+
+/// Running footer summary over the events appended or read so far.
+#[derive(Clone)]
+pub(crate) struct FooterState {
+    event_count: u64,
+    tip: Option<[u8; 32]>,
+    hasher: Sha256,
+    missing_id: bool,
+}
+
+impl FooterState {
+    pub(crate) fn new() -> Self {
+        let mut hasher = Sha256::new();
+        hasher.update(FOOTER_DOMAIN_SEPARATOR);
+        Self {
+            event_count: 0,
+            tip: None,
+            hasher,
+            missing_id: false,
+        }
+    }
+
+    /// Records an event, returning `false` if it has no 32-byte `event_id`.
+    pub(crate) fn record(&mut self, event: &EventJson) -> bool {
+        self.event_count += 1;
+        let id = event
+            .get("event_id")
+            .and_then(|value| serde_json::from_value::<Digest>(value.clone()).ok())
+            .and_then(|digest| digest.to_bytes());
+        match id {
+            Some(id) => {
+                self.hasher.update(id);
+                self.tip = Some(id);
+                true
+            }
+            None => {
+                self.missing_id = true;
+                self.tip = None;
+                false
+            }
+        }
+    }
+
+    /// Returns the footer describing the events recorded so far.
+    pub(crate) fn footer(&self) -> JournalFooter {
+        JournalFooter {
+            event_count: self.event_count,
+            tip_event_id: self.tip.map(|tip| encode_digest(&tip)),
+            ids_digest: encode_digest(&self.hasher.clone().finalize()),
+        }
+    }
+
+    /// Checks a footer read from disk against the events recorded so far.
+    pub(crate) fn check(&self, footer: &JournalFooter) -> Result<(), String> {
+        if self.missing_id {
+            return Err("an event before the footer has no valid event_id".to_string());
+        }
+        let expected = self.footer();
+        if footer.event_count != expected.event_count {
+            return Err(format!(
+                "footer records {} events, read {}",
+                footer.event_count, expected.event_count
+            ));
+        }
+        if footer.tip_event_id != expected.tip_event_id {
+            return Err("footer tip does not match the last event_id".to_string());
+        }
+        if footer.ids_digest != expected.ids_digest {
+            return Err("footer ids digest does not match the events read".to_string());
+        }
+        Ok(())
+    }
+}
+
+fn encode_digest(bytes: &[u8]) -> Digest {
+    let bytes: &[u8; 32] = bytes.try_into().expect("digest slices are 32 bytes");
+    Digest::from_bytes(DigestAlg::Sha256, bytes)
+}
@@ -6,6 +6,18 @@ pub const MAGIC: &[u8; 4] = b"NRJ1";
 /// Current journal format version: `0x0001`.
 pub const VERSION: u16 = 0x0001;
 
+/// Header flag: every frame carries a CRC32C trailer over its payload.
+pub const FLAG_FRAME_CRC32C: u16 = 0x0001;
+
+/// Header flag: the journal may contain [`FrameKind::EventJsonZstd`] frames.
+pub const FLAG_COMPRESSED_FRAMES: u16 = 0x0002;
+
+/// Header flags understood by this implementation.
+pub const KNOWN_FLAGS: u16 = FLAG_FRAME_CRC32C | FLAG_COMPRESSED_FRAMES;
+
+/// Frame CRC32C trailer size in bytes: 4 bytes.
+pub const FRAME_CRC_SIZE: usize = 4;
+
 /// Header size in bytes: 16 bytes.
 pub const HEADER_SIZE: usize = 16;
 
@@ -28,6 +40,12 @@ pub const MAX_PAYLOAD_SIZE: u32 = 16 * 1024 * 1024;
 /// Record frame kind: EventJson.
 pub const FRAME_KIND_EVENT_JSON: u8 = 0x01;
 
+/// Record frame kind: Footer.
+pub const FRAME_KIND_FOOTER: u8 = 0x02;
+
+/// Record frame kind: zstd-compressed EventJson.
+pub const FRAME_KIND_EVENT_JSON_ZSTD: u8 = 0x03;
+
 /// Journal file header (16 bytes).
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct JournalHeader {
@@ -35,7 +53,7 @@ pub struct JournalHeader {
     pub magic: [u8; 4],
     /// Format version: `0x0001`.
     pub version: u16,
-    /// Reserved flags (must be 0).
+    /// Feature flags; only bits in [`KNOWN_FLAGS`] may be set.
     pub flags: u16,
     /// Reserved bytes (must be all zeros).
     pub reserved: [u8; 8],
@@ -52,6 +70,24 @@ impl JournalHeader {
         }
     }
 
+    /// Creates a header whose frames carry CRC32C trailers.
+    pub fn with_frame_crc() -> Self {
+        Self {
+            flags: FLAG_FRAME_CRC32C,
+            ..Self::new()
+        }
+    }
+
+    /// Returns `true` if frames carry a CRC32C trailer.
+    pub fn has_frame_crc(&self) -> bool {
+        self.flags & FLAG_FRAME_CRC32C != 0
+    }
+
+    /// Returns `true` if the journal may contain compressed event frames.
+    pub fn has_compressed_frames(&self) -> bool {
+        self.flags & FLAG_COMPRESSED_FRAMES != 0
+    }
+
     /// Serializes the header to bytes.
     pub fn to_bytes(&self) -> [u8; HEADER_SIZE] {
         let mut bytes = [0u8; HEADER_SIZE];
@@ -88,9 +124,9 @@ impl JournalHeader {
         }
 
         let flags = u16::from_le_bytes([bytes[6], bytes[7]]);
-        if flags != 0 {
+        if flags & !KNOWN_FLAGS != 0 {
             return Err(JournalError::InvalidHeader(format!(
-                "non-zero flags: 0x{:04x}",
+                "unknown flags: 0x{:04x}",
                 flags
             )));
         }
@@ -123,6 +159,12 @@ impl Default for JournalHeader {
 pub enum FrameKind {
     /// EventJson: UTF-8 JSON object representing a canonical event.
     EventJson,
+    /// Footer: fixed-size summary of the events preceding it.
+    Footer,
+    /// EventJsonZstd: an EventJson payload compressed with zstd.
+    ///
+    /// Decoding requires the `compression` cargo feature.
+    EventJsonZstd,
     /// Unknown/unsupported frame kind.
     Unknown(u8),
 }
@@ -132,6 +174,8 @@ impl FrameKind {
     pub fn from_byte(byte: u8) -> Self {
         match byte {
             FRAME_KIND_EVENT_JSON => FrameKind::EventJson,
+            FRAME_KIND_FOOTER => FrameKind::Footer,
+            FRAME_KIND_EVENT_JSON_ZSTD => FrameKind::EventJsonZstd,
             _ => FrameKind::Unknown(byte),
         }
     }
@@ -140,9 +184,16 @@ impl FrameKind {
     pub fn to_byte(self) -> u8 {
         match self {
             FrameKind::EventJson => FRAME_KIND_EVENT_JSON,
+            FrameKind::Footer => FRAME_KIND_FOOTER,
+            FrameKind::EventJsonZstd => FRAME_KIND_EVENT_JSON_ZSTD,
             FrameKind::Unknown(b) => b,
         }
     }
+
+    /// Returns `true` for kinds that carry an event, compressed or not.
+    pub fn is_event(self) -> bool {
+        matches!(self, FrameKind::EventJson | FrameKind::EventJsonZstd)
+    }
 }
 
 /// Record frame header (8 bytes).
@@ -247,12 +298,35 @@ mod tests {
     }
 
     #[test]
-    fn header_rejects_non_zero_flags() {
+    fn header_rejects_unknown_flags() {
         let mut bytes = JournalHeader::new().to_bytes();
-        bytes[6] = 0x01;
+        bytes[6] = 0x04;
         assert!(JournalHeader::from_bytes(&bytes).is_err());
     }
 
+    #[test]
+    fn header_round_trips_frame_crc_flag() {
+        let bytes = JournalHeader::with_frame_crc().to_bytes();
+        assert_eq!(bytes[6], 0x01);
+        let restored = JournalHeader::from_bytes(&bytes).unwrap();
+        assert!(restored.has_frame_crc());
+        assert!(!JournalHeader::new().has_frame_crc());
+    }
+
+    #[test]
+    fn header_round_trips_compressed_frames_flag() {
+        let header = JournalHeader {
+            flags: FLAG_COMPRESSED_FRAMES,
+            ..JournalHeader::new()
+        };
+        let bytes = header.to_bytes();
+        assert_eq!(bytes[6], 0x02);
+        let restored = JournalHeader::from_bytes(&bytes).unwrap();
+        assert!(restored.has_compressed_frames());
+        assert!(!restored.has_frame_crc());
+        assert!(!JournalHeader::new().has_compressed_frames());
+    }
+
     #[test]
     fn header_rejects_non_zero_reserved() {
         let mut bytes = JournalHeader::new().to_bytes();
@@ -283,6 +357,15 @@ mod tests {
         assert!(RecordFrame::from_bytes(&bytes).is_err());
     }
 
+    #[test]
+    fn event_kinds_round_trip_bytes() {
+        for kind in [FrameKind::EventJson, FrameKind::EventJsonZstd] {
+            assert!(kind.is_event());
+            assert_eq!(FrameKind::from_byte(kind.to_byte()), kind);
+        }
+        assert!(!FrameKind::Footer.is_event());
+    }
+
     #[test]
     fn frame_kind_unknown() {
         let kind = FrameKind::from_byte(0xFF);
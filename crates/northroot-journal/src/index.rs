@@ -0,0 +1,157 @@
+use crate::errors::JournalError;
+use crate::event::EventJson;
+use crate::reader::{JournalReader, ReadMode};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+/// Sidecar index format version.
+const INDEX_VERSION: u32 = 1;
+
+/// Map from `event_id` to the byte offset of the frame holding the event.
+///
+/// An index is derived data: it can always be rebuilt from the journal with
+/// [`build_index`], and lookups should be read back with
+/// [`JournalReader::read_at_offset`], which re-validates the frame. When an
+/// `event_id` occurs more than once, the first occurrence is indexed.
+///
+/// Indexes persist as a JSON sidecar next to the journal (`events.nrj.idx`
+/// for `events.nrj`); see [`sidecar_path`](Self::sidecar_path). Each index
+/// records the journal's size and modification time when it was built, so
+/// [`is_stale`](Self::is_stale) can detect a journal changed since.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JournalIndex {
+    version: u32,
+    journal_len: u64,
+    journal_mtime_ns: Option<u64>,
+    offsets: BTreeMap<String, u64>,
+}
+
+impl JournalIndex {
+    pub(crate) fn new() -> Self {
+        Self {
+            version: INDEX_VERSION,
+            journal_len: 0,
+            journal_mtime_ns: None,
+            offsets: BTreeMap::new(),
+        }
+    }
+
+    /// Records the frame offset of an event, keeping the first occurrence.
+    pub(crate) fn insert(&mut self, event: &EventJson, offset: u64) {
+        if let Some(event_id) = event
+            .get("event_id")
+            .and_then(|id| id.get("b64"))
+            .and_then(|b64| b64.as_str())
+        {
+            self.offsets.entry(event_id.to_string()).or_insert(offset);
+        }
+    }
+
+    /// Records the journal's current size and modification time.
+    pub(crate) fn stamp(&mut self, journal: &Path) -> Result<(), JournalError> {
+        (self.journal_len, self.journal_mtime_ns) = file_stamp(journal)?;
+        Ok(())
+    }
+
+    /// Returns `true` if the journal's size or modification time differs from
+    /// when the index was built.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JournalError::Io`] if the journal's metadata cannot be read.
+    pub fn is_stale(&self, journal: &Path) -> Result<bool, JournalError> {
+        Ok(file_stamp(journal)? != (self.journal_len, self.journal_mtime_ns))
+    }
+
+    /// Returns the sidecar path for a journal: the journal path with `.idx`
+    /// appended.
+    pub fn sidecar_path(journal: &Path) -> PathBuf {
+        let mut path = journal.as_os_str().to_owned();
+        path.push(".idx");
+        PathBuf::from(path)
+    }
+
+    /// Returns the frame offset of the event with the given `event_id.b64`.
+    pub fn get(&self, event_id: &str) -> Option<u64> {
+        self.offsets.get(event_id).copied()
+    }
+
+    /// Returns the number of indexed events.
+    pub fn len(&self) -> usize {
+        self.offsets.len()
+    }
+
+    /// Returns `true` if no events are indexed.
+    pub fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
+
+    /// Writes the index to the journal's sidecar file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JournalError`] if the sidecar cannot be written.
+    pub fn save(&self, journal: &Path) -> Result<(), JournalError> {
+        fs::write(Self::sidecar_path(journal), serde_json::to_vec(self)?)?;
+        Ok(())
+    }
+}
+
+/// Scans a journal once and indexes every event by `event_id.b64`.
+///
+/// Events without a string `event_id.b64` are not indexed.
+///
+/// # Errors
+///
+/// Returns [`JournalError`] if the journal cannot be read in strict mode.
+pub fn build_index(journal: &Path) -> Result<JournalIndex, JournalError> {
+    let mut index = JournalIndex::new();
+    index.stamp(journal)?;
+    let mut reader = JournalReader::open(journal, ReadMode::Strict)?;
+    loop {
+        let offset = reader.position();
+        let Some(event) = reader.read_event()? else {
+            break;
+        };
+        index.insert(&event, offset);
+    }
+    Ok(index)
+}
+
+/// Loads a journal's sidecar index, or `None` if it has no sidecar.
+///
+/// # Errors
+///
+/// Returns [`JournalError::InvalidIndex`] if the sidecar exists but cannot
+/// be parsed or has an unsupported version.
+pub fn load_index(journal: &Path) -> Result<Option<JournalIndex>, JournalError> {
+    let bytes = match fs::read(JournalIndex::sidecar_path(journal)) {
+        Ok(bytes) => bytes,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let index: JournalIndex =
+        serde_json::from_slice(&bytes).map_err(|e| JournalError::InvalidIndex(e.to_string()))?;
+    if index.version != INDEX_VERSION {
+        return Err(JournalError::InvalidIndex(format!(
+            "unsupported version {}, expected {}",
+            index.version, INDEX_VERSION
+        )));
+    }
+    Ok(Some(index))
+}
+
+/// Returns a file's length and modification time in nanoseconds, if known.
+fn file_stamp(path: &Path) -> Result<(u64, Option<u64>), JournalError> {
+    let metadata = fs::metadata(path)?;
+    let mtime_ns = metadata
+        .modified()
+        .ok()
+        .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+        .and_then(|since_epoch| u64::try_from(since_epoch.as_nanos()).ok());
+    Ok((metadata.len(), mtime_ns))
+}
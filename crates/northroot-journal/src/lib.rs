@@ -44,7 +44,15 @@
 //!
 //! - [`JournalWriter`] - Write events to journal files
 //! - [`JournalReader`] - Read events from journal files
+//! - [`FrameReader`] - Read raw frames without parsing JSON
+//! - [`ReverseJournalReader`] - Read events newest-first
+//! - [`ChainedReader`] - Read rotated journal files as one stream
+//! - [`DedupReader`] - Skip events whose `event_id` was already read
+//! - `AsyncJournalWriter` / `AsyncJournalReader` - Write and read events from async code (`tokio` feature)
+//! - [`JournalIndex`] - Random access to events by `event_id`
 //! - [`verify_event_id`] - Verify event identity
+//! - [`verify_chain`] - Verify `prev_event_id` linkage across a journal
+//! - [`verify_temporal_order`] - Check `occurred_at` is non-decreasing within a skew tolerance
 //!
 //! ## See Also
 //!
@@ -56,12 +64,23 @@
 
 #![deny(missing_docs)]
 
+/// Async journal reader implementation.
+#[cfg(feature = "tokio")]
+pub mod async_reader;
+/// Async journal writer implementation.
+#[cfg(feature = "tokio")]
+pub mod async_writer;
+mod compression;
 /// Error types for journal operations.
 pub mod errors;
 /// Event JSON type alias and helpers.
 pub mod event;
+/// Footer frame summarizing the events before it.
+pub mod footer;
 /// Frame structure and serialization.
 pub mod frame;
+/// Sidecar index from event_id to frame offset.
+pub mod index;
 /// Journal reader implementation.
 pub mod reader;
 /// Verification helpers for journal events.
@@ -69,9 +88,21 @@ pub mod verification;
 /// Journal writer implementation.
 pub mod writer;
 
+#[cfg(feature = "tokio")]
+pub use async_reader::AsyncJournalReader;
+#[cfg(feature = "tokio")]
+pub use async_writer::AsyncJournalWriter;
 pub use errors::JournalError;
 pub use event::{EventJson, EventObject};
+pub use footer::JournalFooter;
 pub use frame::{FrameKind, JournalHeader, RecordFrame};
-pub use reader::{JournalReader, ReadMode};
-pub use verification::verify_event_id;
+pub use index::{build_index, load_index, JournalIndex};
+pub use reader::{
+    ChainedReader, DedupReader, Events, FrameReader, JournalReader, ReadMode, ReverseJournalReader,
+    TypeCounts,
+};
+pub use verification::{
+    verify_chain, verify_event_id, verify_temporal_order, ChainBreak, ChainVerifier,
+    OrderViolation, TemporalOrderChecker,
+};
 pub use writer::{JournalWriter, WriteOptions};
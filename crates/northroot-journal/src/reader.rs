@@ -1,11 +1,16 @@
 //! Journal reader implementation.
 
+use crate::compression;
 use crate::errors::JournalError;
 use crate::event::EventJson;
-use crate::frame::{FrameKind, JournalHeader, RecordFrame};
+use crate::footer::{FooterState, JournalFooter, FOOTER_SIZE};
+use crate::frame::{FrameKind, JournalHeader, RecordFrame, FRAME_CRC_SIZE};
 use northroot_canonical::parse_json_strict;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashSet, VecDeque};
 use std::fs::File;
 use std::io::{self, Read, Seek};
+use std::iter::FusedIterator;
 use std::path::Path;
 
 /// Read mode for handling truncation.
@@ -14,14 +19,41 @@ pub enum ReadMode {
     /// Strict mode: truncated frames are errors.
     Strict,
     /// Permissive mode: truncation is treated as end-of-file.
+    ///
+    /// The reader stays positioned at the start of the incomplete frame, so
+    /// once a concurrent writer finishes it a later read returns it.
     Permissive,
+    /// Repair mode: damaged frames are skipped and reading resumes at the
+    /// next valid frame.
+    ///
+    /// Applies to [`JournalReader::read_event`]; frame-level readers treat it
+    /// like [`ReadMode::Strict`]. See [`JournalReader::skipped_frames`].
+    Repair,
 }
 
 /// Journal reader for reading events from a journal file.
 ///
-/// The reader supports two modes:
+/// The reader supports three modes:
 /// - [`ReadMode::Strict`] - Truncated frames are errors
 /// - [`ReadMode::Permissive`] - Truncation is treated as end-of-file
+/// - [`ReadMode::Repair`] - Damaged frames are skipped
+///
+/// When the header sets [`FLAG_FRAME_CRC32C`](crate::frame::FLAG_FRAME_CRC32C),
+/// each payload is checked against its CRC32C trailer. A mismatch is
+/// [`JournalError::FrameCorrupt`] in strict mode and ends reading in
+/// permissive mode, like truncation.
+///
+/// Footer frames are checked against the events read before them as they are
+/// encountered; see [`is_complete`](Self::is_complete).
+///
+/// # Reading while another process appends
+///
+/// The reader opens the file read-only and takes no lock, so it never blocks
+/// a [`JournalWriter`](crate::JournalWriter). In permissive mode it returns
+/// every frame that is complete when read and reports the partially written
+/// tail as end-of-file; calling [`read_event`](Self::read_event) again later
+/// picks up frames appended since. Strict mode reports the same tail as
+/// [`JournalError::TruncatedFrame`].
 ///
 /// # Example
 ///
@@ -43,6 +75,11 @@ pub struct JournalReader {
     file: File,
     mode: ReadMode,
     position: u64,
+    frame_crc: bool,
+    footer_state: FooterState,
+    last_footer: Option<JournalFooter>,
+    complete: bool,
+    skipped: Vec<u64>,
 }
 
 impl JournalReader {
@@ -50,6 +87,86 @@ impl JournalReader {
     pub fn position(&self) -> u64 {
         self.position
     }
+
+    /// Returns the most recent footer read so far, if any.
+    ///
+    /// Footers returned here have already matched the events before them.
+    pub fn last_footer(&self) -> Option<&JournalFooter> {
+        self.last_footer.as_ref()
+    }
+
+    /// Returns `true` if the most recently read frame was a matching footer.
+    ///
+    /// Once [`read_event`](Self::read_event) has returned `Ok(None)`, this
+    /// reports whether the journal ends with a footer covering every event,
+    /// i.e. whether it was closed by [`JournalWriter::finish`](crate::JournalWriter::finish)
+    /// with footers enabled and not truncated or extended since.
+    pub fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    /// Reads the footer frame at the end of the file, if the file ends with one.
+    ///
+    /// This is a constant-time sanity check that reads only the last frame;
+    /// the sequential read position is unaffected. The footer is not checked
+    /// against the events before it, so it only reports what the writer
+    /// recorded. For a checked answer, read to the end and use
+    /// [`is_complete`](Self::is_complete) and [`last_footer`](Self::last_footer).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use northroot_journal::{JournalReader, ReadMode};
+    ///
+    /// let reader = JournalReader::open("events.nrj", ReadMode::Strict)?;
+    /// if let Some(footer) = reader.footer()? {
+    ///     println!("{} events", footer.event_count);
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JournalError`] if the file cannot be read, or if the trailing
+    /// frame is a footer whose CRC32C trailer does not match.
+    pub fn footer(&self) -> Result<Option<JournalFooter>, JournalError> {
+        let crc_size = if self.frame_crc { FRAME_CRC_SIZE } else { 0 };
+        let frame_size = (RecordFrame::FRAME_HEADER_SIZE + FOOTER_SIZE + crc_size) as u64;
+        let mut file = &self.file;
+        let file_size = file.metadata()?.len();
+        if file_size < JournalHeader::HEADER_SIZE as u64 + frame_size {
+            return Ok(None);
+        }
+
+        let offset = file_size - frame_size;
+        let mut bytes = vec![0u8; frame_size as usize];
+        file.seek(io::SeekFrom::Start(offset))?;
+        file.read_exact(&mut bytes)?;
+
+        let (header, rest) = bytes.split_at(RecordFrame::FRAME_HEADER_SIZE);
+        let (payload, crc) = rest.split_at(FOOTER_SIZE);
+        match RecordFrame::from_bytes(header) {
+            Ok(frame) if frame.kind == FrameKind::Footer && frame.len as usize == FOOTER_SIZE => {}
+            _ => return Ok(None),
+        }
+        if self.frame_crc && crc != crc32c::crc32c(payload).to_le_bytes() {
+            return Err(JournalError::FrameCorrupt { offset });
+        }
+        JournalFooter::from_bytes(payload).map(Some)
+    }
+
+    /// Returns the offsets of frames skipped in [`ReadMode::Repair`].
+    ///
+    /// Each entry is where a damaged frame (bad header, length, CRC, or
+    /// payload, or a footer that no longer matches) started. Always empty in
+    /// the other modes.
+    pub fn skipped_frames(&self) -> &[u64] {
+        &self.skipped
+    }
+
+    pub(crate) fn footer_state(&self) -> &FooterState {
+        &self.footer_state
+    }
 }
 
 impl JournalReader {
@@ -70,18 +187,24 @@ impl JournalReader {
     /// # Errors
     ///
     /// Returns [`JournalError`] if:
-    /// - File cannot be opened
+    /// - File cannot be opened ([`JournalError::Open`], naming the path)
     /// - File header is invalid
     /// - I/O error occurs
     pub fn open<P: AsRef<Path>>(path: P, mode: ReadMode) -> Result<Self, JournalError> {
-        let mut file = File::open(path)?;
-        let _header = Self::read_header(&mut file)?;
+        let path = path.as_ref();
+        let mut file = File::open(path).map_err(|e| JournalError::open(path, e))?;
+        let header = Self::read_header(&mut file)?;
         let position = JournalHeader::HEADER_SIZE as u64;
 
         Ok(Self {
             file,
             mode,
             position,
+            frame_crc: header.has_frame_crc(),
+            footer_state: FooterState::new(),
+            last_footer: None,
+            complete: false,
+            skipped: Vec::new(),
         })
     }
 
@@ -96,62 +219,106 @@ impl JournalReader {
     ///
     /// Returns `Ok(None)` when end-of-file is reached (or truncation in permissive mode).
     pub fn read_frame(&mut self) -> Result<Option<(FrameKind, Vec<u8>)>, JournalError> {
-        self.file.seek(io::SeekFrom::Start(self.position))?;
+        let mut payload = Vec::new();
+        Ok(self
+            .read_frame_into(&mut payload)?
+            .map(|kind| (kind, payload)))
+    }
+
+    /// Reads the next frame payload into `payload`, reusing its allocation.
+    fn read_frame_into(
+        &mut self,
+        payload: &mut Vec<u8>,
+    ) -> Result<Option<FrameKind>, JournalError> {
+        // Advance `self.position` only once the whole frame has been read, so
+        // an incomplete tail is re-read from its start on the next call.
+        let frame_start = self.position;
+        let mut position = frame_start;
+        self.file.seek(io::SeekFrom::Start(position))?;
 
         // Check if we're at EOF before trying to read
         let file_size = self.file.metadata()?.len();
-        if self.position >= file_size {
+        if position >= file_size {
             return Ok(None);
         }
 
         // Read frame header
         let mut frame_header_bytes = [0u8; RecordFrame::FRAME_HEADER_SIZE];
-        match self.file.read_exact(&mut frame_header_bytes) {
-            Ok(()) => {}
-            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => {
-                if self.mode == ReadMode::Permissive {
-                    return Ok(None);
-                }
-                return Err(JournalError::TruncatedFrame {
-                    offset: self.position,
-                });
-            }
-            Err(e) => return Err(e.into()),
+        if let Err(e) = self.file.read_exact(&mut frame_header_bytes) {
+            return read_failure(e, self.mode, position);
         }
-
-        let frame = RecordFrame::from_bytes(&frame_header_bytes).map_err(|e| match e {
-            JournalError::InvalidFrame { offset: _, reason } => JournalError::InvalidFrame {
-                offset: self.position,
-                reason,
-            },
-            other => other,
-        })?;
-
-        self.position += RecordFrame::FRAME_HEADER_SIZE as u64;
+        let frame = parse_frame_header(&frame_header_bytes, position)?;
+        position += RecordFrame::FRAME_HEADER_SIZE as u64;
 
         // Read payload
-        let mut payload = vec![0u8; frame.len as usize];
-        match self.file.read_exact(&mut payload) {
-            Ok(()) => {}
-            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => {
-                if self.mode == ReadMode::Permissive {
-                    return Ok(None);
-                }
-                return Err(JournalError::TruncatedFrame {
-                    offset: self.position,
-                });
+        payload.clear();
+        payload.resize(frame.len as usize, 0);
+        if let Err(e) = self.file.read_exact(payload) {
+            return read_failure(e, self.mode, position);
+        }
+        position += frame.len as u64;
+
+        if self.frame_crc {
+            let mut crc_bytes = [0u8; FRAME_CRC_SIZE];
+            if let Err(e) = self.file.read_exact(&mut crc_bytes) {
+                return read_failure(e, self.mode, position);
             }
-            Err(e) => return Err(e.into()),
+            if !crc_matches(payload, crc_bytes) {
+                return corrupt_frame(self.mode, frame_start);
+            }
+            position += FRAME_CRC_SIZE as u64;
         }
 
-        self.position += frame.len as u64;
+        self.position = position;
+        Ok(Some(frame.kind))
+    }
 
-        Ok(Some((frame.kind, payload)))
+    /// Reads the event whose frame starts at `offset`.
+    ///
+    /// Used with a [`JournalIndex`](crate::JournalIndex) for random access.
+    /// The frame is fully validated, but footers are not checked and the
+    /// sequential read [`position`](Self::position) is left unchanged.
+    ///
+    /// Returns `Ok(None)` if `offset` is at or past end-of-file (or at a
+    /// truncated frame in permissive mode).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JournalError::InvalidFrame`] if the frame at `offset` is not
+    /// an EventJson frame, or any error [`read_event`](Self::read_event)
+    /// would return for it.
+    pub fn read_at_offset(&mut self, offset: u64) -> Result<Option<EventJson>, JournalError> {
+        if offset < JournalHeader::HEADER_SIZE as u64 {
+            return Err(JournalError::InvalidFrame {
+                offset,
+                reason: "offset is inside the file header".to_string(),
+            });
+        }
+
+        let position = self.position;
+        self.position = offset;
+        let mut payload = Vec::new();
+        let kind = self.read_frame_into(&mut payload);
+        self.position = position;
+
+        match kind? {
+            None => Ok(None),
+            Some(kind) if kind.is_event() => parse_event_frame(kind, &payload).map(Some),
+            Some(kind) => Err(JournalError::InvalidFrame {
+                offset,
+                reason: format!(
+                    "expected an EventJson frame, found kind {:#04x}",
+                    kind.to_byte()
+                ),
+            }),
+        }
     }
 
     /// Reads the next event JSON from the journal.
     ///
     /// Skips unknown frame kinds and returns `Ok(None)` at end-of-file.
+    /// Footer frames are not returned; each is checked against the events
+    /// read before it.
     ///
     /// # Example
     ///
@@ -170,25 +337,654 @@ impl JournalReader {
     /// Returns [`JournalError`] if:
     /// - Frame structure is invalid
     /// - JSON parsing fails
+    /// - A footer does not match the events before it
     /// - Truncation detected (in strict mode)
     /// - I/O error occurs
     pub fn read_event(&mut self) -> Result<Option<EventJson>, JournalError> {
+        Ok(self.read_event_with_offset()?.map(|(event, _, _)| event))
+    }
+
+    /// Reads the next event along with where its frame lives in the file.
+    ///
+    /// Returns the event, the offset its frame starts at, and the frame's
+    /// length in bytes (header, payload, and CRC when present). Passing the
+    /// offset to [`read_at_offset`](Self::read_at_offset) decodes the same
+    /// event, which lets tools build their own indexes. Otherwise behaves
+    /// like [`read_event`](Self::read_event).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JournalError`] under the same conditions as
+    /// [`read_event`](Self::read_event).
+    pub fn read_event_with_offset(
+        &mut self,
+    ) -> Result<Option<(EventJson, u64, u32)>, JournalError> {
+        let mut frame_offset = self.position;
         loop {
+            match self.read_next_event(&mut frame_offset) {
+                Err(e) if self.mode == ReadMode::Repair && is_frame_damage(&e) => {
+                    self.resync(frame_offset)?;
+                }
+                result => {
+                    // Frames are bounded by MAX_PAYLOAD_SIZE, so the length fits.
+                    let length = (self.position - frame_offset) as u32;
+                    return Ok(result?.map(|event| (event, frame_offset, length)));
+                }
+            }
+        }
+    }
+
+    /// Reads frames until the next event, recording where each frame starts.
+    fn read_next_event(
+        &mut self,
+        frame_offset: &mut u64,
+    ) -> Result<Option<EventJson>, JournalError> {
+        loop {
+            let offset = self.position;
+            *frame_offset = offset;
             match self.read_frame()? {
                 None => return Ok(None),
-                Some((FrameKind::EventJson, payload)) => {
-                    // Validate UTF-8
-                    let utf8_str = std::str::from_utf8(&payload)?;
-                    // Parse JSON before object keys can collapse.
-                    let json: EventJson = parse_json_strict(utf8_str)
-                        .map_err(|e| JournalError::InvalidJson(e.to_string()))?;
+                Some((kind, payload)) if kind.is_event() => {
+                    let json = parse_event_frame(kind, &payload)?;
+                    self.footer_state.record(&json);
+                    self.complete = false;
                     return Ok(Some(json));
                 }
-                Some((FrameKind::Unknown(_), _)) => {
+                Some((FrameKind::Footer, payload)) => {
+                    match check_footer(&self.footer_state, &payload, offset) {
+                        Ok(footer) => {
+                            self.last_footer = Some(footer);
+                            self.complete = true;
+                        }
+                        Err(JournalError::FooterMismatch { .. })
+                            if self.mode == ReadMode::Repair =>
+                        {
+                            // The frame is intact; only its summary is stale.
+                            self.skipped.push(offset);
+                        }
+                        Err(e) => return Err(e),
+                    }
+                }
+                Some(_) => {
                     // Skip unknown frame kinds
                     continue;
                 }
             }
         }
     }
+
+    /// Converts the reader into an iterator over its remaining events.
+    ///
+    /// Each item is the result of one [`read_event`](Self::read_event) call.
+    /// Iteration ends at end-of-file or after the first error, so adapters
+    /// such as `take`, `filter`, and `collect::<Result<Vec<_>, _>>()` work
+    /// as expected. To keep polling a journal that is still growing, call
+    /// `read_event` directly instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use northroot_journal::{JournalReader, ReadMode};
+    ///
+    /// let reader = JournalReader::open("events.nrj", ReadMode::Strict)?;
+    /// let first_ten = reader.events().take(10).collect::<Result<Vec<_>, _>>()?;
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn events(self) -> Events {
+        Events { reader: Some(self) }
+    }
+
+    /// Counts the event frames from the current position to end-of-file.
+    ///
+    /// Only frame headers are read: payloads are skipped without being read,
+    /// CRC-checked, or parsed. Use this when only the number of events is
+    /// needed. The reader is left at end-of-file, and footers are not checked.
+    /// [`ReadMode::Repair`] is treated like [`ReadMode::Strict`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JournalError`] for an invalid frame header, a frame that
+    /// runs past end-of-file in strict mode, or an I/O error.
+    pub fn count_frames(&mut self) -> Result<u64, JournalError> {
+        let file_size = self.file.metadata()?.len();
+        let crc_size = if self.frame_crc { FRAME_CRC_SIZE } else { 0 } as u64;
+        let mut header = [0u8; RecordFrame::FRAME_HEADER_SIZE];
+        let mut count = 0;
+        while self.position < file_size {
+            self.file.seek(io::SeekFrom::Start(self.position))?;
+            if let Err(e) = self.file.read_exact(&mut header) {
+                read_failure(e, self.mode, self.position)?;
+                break;
+            }
+            let frame = parse_frame_header(&header, self.position)?;
+            let payload_start = self.position + RecordFrame::FRAME_HEADER_SIZE as u64;
+            let end = payload_start + frame.len as u64 + crc_size;
+            if end > file_size {
+                if self.mode == ReadMode::Permissive {
+                    break;
+                }
+                return Err(JournalError::TruncatedFrame {
+                    offset: payload_start,
+                });
+            }
+            if frame.kind.is_event() {
+                count += 1;
+            }
+            self.position = end;
+        }
+        Ok(count)
+    }
+
+    /// Counts events by `event_type` from the current position to
+    /// end-of-file.
+    ///
+    /// Each payload is CRC-checked and decompressed as in
+    /// [`read_event`](Self::read_event), but only its `event_type` field is
+    /// decoded; no [`EventJson`] value is built and payloads are not otherwise
+    /// validated. The reader is left at end-of-file, and footers are not
+    /// checked. [`ReadMode::Repair`] is treated like [`ReadMode::Strict`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use northroot_journal::{JournalReader, ReadMode};
+    ///
+    /// let mut reader = JournalReader::open("events.nrj", ReadMode::Strict)?;
+    /// let counts = reader.scan_types()?;
+    /// for (event_type, count) in &counts.by_type {
+    ///     println!("{event_type}: {count}");
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JournalError`] under the same conditions as
+    /// [`read_frame`](Self::read_frame), or if a payload is not a JSON object.
+    pub fn scan_types(&mut self) -> Result<TypeCounts, JournalError> {
+        #[derive(Deserialize)]
+        struct EventType {
+            event_type: Option<serde_json::Value>,
+        }
+
+        let mut counts = TypeCounts::default();
+        let mut payload = Vec::new();
+        while let Some(kind) = self.read_frame_into(&mut payload)? {
+            if !kind.is_event() {
+                continue;
+            }
+            let json = compression::event_payload(kind, &payload)?;
+            let event: EventType = serde_json::from_slice(&json)?;
+            counts.events += 1;
+            match event.event_type {
+                Some(serde_json::Value::String(event_type)) => {
+                    *counts.by_type.entry(event_type).or_default() += 1;
+                }
+                _ => counts.untyped += 1,
+            }
+        }
+        Ok(counts)
+    }
+
+    /// Records a damaged frame and moves to the next offset after it that
+    /// holds a valid frame, or to end-of-file if there is none.
+    fn resync(&mut self, damaged: u64) -> Result<(), JournalError> {
+        self.skipped.push(damaged);
+        let file_size = self.file.metadata()?.len();
+        let mut payload = Vec::new();
+        for candidate in damaged + 1..file_size {
+            self.position = candidate;
+            let valid = match self.read_frame_into(&mut payload) {
+                Ok(Some(kind)) if kind.is_event() => {
+                    parse_event_frame(kind, &payload).is_ok_and(|event| event.is_object())
+                }
+                Ok(Some(FrameKind::Footer)) => payload.len() == FOOTER_SIZE,
+                Ok(_) => false,
+                Err(JournalError::Io(e)) => return Err(e.into()),
+                Err(_) => false,
+            };
+            if valid {
+                self.position = candidate;
+                return Ok(());
+            }
+        }
+        self.position = file_size;
+        Ok(())
+    }
+}
+
+/// Event counts returned by [`JournalReader::scan_types`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TypeCounts {
+    /// Number of events scanned.
+    pub events: u64,
+    /// Number of events per `event_type`.
+    pub by_type: BTreeMap<String, u64>,
+    /// Number of events with no string `event_type`.
+    pub untyped: u64,
+}
+
+/// Iterator over a journal's events, returned by [`JournalReader::events`].
+pub struct Events {
+    reader: Option<JournalReader>,
+}
+
+impl Iterator for Events {
+    type Item = Result<EventJson, JournalError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.reader.as_mut()?.read_event().transpose();
+        if !matches!(result, Some(Ok(_))) {
+            // End-of-file or an error ends iteration for good.
+            self.reader = None;
+        }
+        result
+    }
+}
+
+impl FusedIterator for Events {}
+
+/// Returns `true` for errors caused by damaged frame bytes rather than I/O.
+fn is_frame_damage(error: &JournalError) -> bool {
+    matches!(
+        error,
+        JournalError::InvalidFrame { .. }
+            | JournalError::TruncatedFrame { .. }
+            | JournalError::FrameCorrupt { .. }
+            | JournalError::InvalidUtf8(_)
+            | JournalError::InvalidJson(_)
+            | JournalError::JsonParse(_)
+            | JournalError::Compression(_)
+    )
+}
+
+/// Maps a failed frame read: a short read is truncation, which permissive
+/// mode reports as end-of-file.
+pub(crate) fn read_failure(
+    error: io::Error,
+    mode: ReadMode,
+    offset: u64,
+) -> Result<Option<FrameKind>, JournalError> {
+    if error.kind() != io::ErrorKind::UnexpectedEof {
+        return Err(error.into());
+    }
+    if mode == ReadMode::Permissive {
+        return Ok(None);
+    }
+    Err(JournalError::TruncatedFrame { offset })
+}
+
+/// Reports a CRC32C mismatch, which permissive mode treats like truncation.
+pub(crate) fn corrupt_frame(
+    mode: ReadMode,
+    offset: u64,
+) -> Result<Option<FrameKind>, JournalError> {
+    if mode == ReadMode::Permissive {
+        return Ok(None);
+    }
+    Err(JournalError::FrameCorrupt { offset })
+}
+
+pub(crate) fn crc_matches(payload: &[u8], crc_bytes: [u8; FRAME_CRC_SIZE]) -> bool {
+    u32::from_le_bytes(crc_bytes) == crc32c::crc32c(payload)
+}
+
+/// Parses a frame header, attributing errors to the frame at `offset`.
+pub(crate) fn parse_frame_header(bytes: &[u8], offset: u64) -> Result<RecordFrame, JournalError> {
+    RecordFrame::from_bytes(bytes).map_err(|e| match e {
+        JournalError::InvalidFrame { offset: _, reason } => {
+            JournalError::InvalidFrame { offset, reason }
+        }
+        other => other,
+    })
+}
+
+/// Checks a footer frame at `offset` against the events recorded so far.
+pub(crate) fn check_footer(
+    state: &FooterState,
+    payload: &[u8],
+    offset: u64,
+) -> Result<JournalFooter, JournalError> {
+    let footer = JournalFooter::from_bytes(payload).map_err(|e| match e {
+        JournalError::InvalidFrame { offset: _, reason } => {
+            JournalError::InvalidFrame { offset, reason }
+        }
+        other => other,
+    })?;
+    state
+        .check(&footer)
+        .map_err(|reason| JournalError::FooterMismatch { offset, reason })?;
+    Ok(footer)
+}
+
+/// Parses an EventJson or EventJsonZstd frame payload.
+pub(crate) fn parse_event_frame(
+    kind: FrameKind,
+    payload: &[u8],
+) -> Result<EventJson, JournalError> {
+    let payload = compression::event_payload(kind, payload)?;
+    // Validate UTF-8
+    let utf8_str = std::str::from_utf8(&payload)?;
+    // Parse JSON before object keys can collapse.
+    parse_json_strict(utf8_str).map_err(|e| JournalError::InvalidJson(e.to_string()))
+}
+
+/// Journal reader that yields events newest-first.
+///
+/// Frames carry only a leading length, so they cannot be walked backwards
+/// directly. [`open`](Self::open) scans the journal forward once, recording
+/// the offset of every EventJson frame, and [`read_prev`](Self::read_prev)
+/// then decodes events from the end of that table. Frames appended after
+/// `open` are not seen.
+///
+/// A truncated or corrupt trailing frame fails `open` in strict mode and is
+/// excluded in permissive mode. Footer frames are skipped without being
+/// checked; use [`JournalReader`] to validate them.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use northroot_journal::{ReadMode, ReverseJournalReader};
+///
+/// let mut reader = ReverseJournalReader::open("events.nrj", ReadMode::Strict)?;
+/// for _ in 0..10 {
+///     let Some(event) = reader.read_prev()? else { break };
+///     println!("Event ID: {}", event["event_id"]);
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct ReverseJournalReader {
+    reader: JournalReader,
+    offsets: Vec<u64>,
+    payload: Vec<u8>,
+}
+
+impl ReverseJournalReader {
+    /// Opens a journal and indexes its event frames for reverse reading.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JournalError`] under the same conditions as
+    /// [`JournalReader::open`], or if a frame cannot be read while indexing.
+    pub fn open<P: AsRef<Path>>(path: P, mode: ReadMode) -> Result<Self, JournalError> {
+        let mut reader = JournalReader::open(path, mode)?;
+        let mut payload = Vec::new();
+        let mut offsets = Vec::new();
+        loop {
+            let offset = reader.position();
+            match reader.read_frame_into(&mut payload)? {
+                None => break,
+                Some(kind) if kind.is_event() => offsets.push(offset),
+                Some(_) => {}
+            }
+        }
+
+        Ok(Self {
+            reader,
+            offsets,
+            payload,
+        })
+    }
+
+    /// Returns the number of events not yet returned.
+    pub fn remaining(&self) -> usize {
+        self.offsets.len()
+    }
+
+    /// Reads the previous event, starting from the last one in the journal.
+    ///
+    /// Returns `Ok(None)` once the first event has been returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JournalError`] if the frame can no longer be read or its
+    /// payload is not valid event JSON.
+    pub fn read_prev(&mut self) -> Result<Option<EventJson>, JournalError> {
+        let Some(offset) = self.offsets.pop() else {
+            return Ok(None);
+        };
+        self.reader.position = offset;
+        match self.reader.read_frame_into(&mut self.payload)? {
+            Some(kind) if kind.is_event() => parse_event_frame(kind, &self.payload).map(Some),
+            _ => Err(JournalError::InvalidFrame {
+                offset,
+                reason: "indexed event frame changed since open".to_string(),
+            }),
+        }
+    }
+}
+
+/// Journal reader that streams events across an ordered list of files.
+///
+/// Rotated journals (`events-001.nrj`, `events-002.nrj`, ...) read as one
+/// logical stream: when one file reaches end-of-file, reading continues at
+/// the first frame of the next. Every file is opened, and its header
+/// validated, by [`open`](Self::open), and all are read with the same
+/// [`ReadMode`]. Footers are checked per file, against that file's events.
+///
+/// Events pass through unchanged, so a `prev_event_id` chain that spans the
+/// files can be checked with a single [`ChainVerifier`](crate::ChainVerifier).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use northroot_journal::{ChainedReader, ReadMode};
+///
+/// let mut reader =
+///     ChainedReader::open(["events-001.nrj", "events-002.nrj"], ReadMode::Strict)?;
+/// while let Some(event) = reader.read_event()? {
+///     println!("Event ID: {}", event["event_id"]);
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct ChainedReader {
+    readers: Vec<JournalReader>,
+    current: usize,
+}
+
+impl ChainedReader {
+    /// Opens every journal in `paths`, in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JournalError`] under the same conditions as
+    /// [`JournalReader::open`], for the first file that fails.
+    pub fn open<I, P>(paths: I, mode: ReadMode) -> Result<Self, JournalError>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<Path>,
+    {
+        let readers = paths
+            .into_iter()
+            .map(|path| JournalReader::open(path, mode))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            readers,
+            current: 0,
+        })
+    }
+
+    /// Returns the index, within the paths given to `open`, of the file
+    /// being read.
+    pub fn current_file(&self) -> usize {
+        self.current
+    }
+
+    /// Reads the next event, moving on to the next file at end-of-file.
+    ///
+    /// Returns `Ok(None)` once the last file is exhausted.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error [`JournalReader::read_event`] returns for the
+    /// current file.
+    pub fn read_event(&mut self) -> Result<Option<EventJson>, JournalError> {
+        while let Some(reader) = self.readers.get_mut(self.current) {
+            if let Some(event) = reader.read_event()? {
+                return Ok(Some(event));
+            }
+            if self.current + 1 == self.readers.len() {
+                break;
+            }
+            self.current += 1;
+        }
+        Ok(None)
+    }
+}
+
+/// Journal reader that skips events whose `event_id` was already returned.
+///
+/// Event IDs are content-derived, so a retried write appends an identical
+/// event under the same ID. Wrapping a [`JournalReader`] in a `DedupReader`
+/// yields each such event once, at its first occurrence. IDs are compared by
+/// `event_id.b64`, as in [`JournalIndex`](crate::JournalIndex); events without
+/// one are always returned.
+///
+/// By default every ID read is remembered. On very large journals, use
+/// [`with_capacity`](Self::with_capacity) to remember only the most recent
+/// IDs; a duplicate is then skipped only while its first occurrence is among
+/// them.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use northroot_journal::{DedupReader, JournalReader, ReadMode};
+///
+/// let reader = JournalReader::open("events.nrj", ReadMode::Strict)?;
+/// let mut reader = DedupReader::with_capacity(reader, 100_000);
+/// while let Some(event) = reader.read_event()? {
+///     println!("Event ID: {}", event["event_id"]);
+/// }
+/// println!("{} duplicates skipped", reader.duplicates());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct DedupReader {
+    reader: JournalReader,
+    seen: HashSet<String>,
+    order: VecDeque<String>,
+    capacity: Option<usize>,
+    duplicates: u64,
+}
+
+impl DedupReader {
+    /// Wraps `reader`, remembering every `event_id` read.
+    pub fn new(reader: JournalReader) -> Self {
+        Self {
+            reader,
+            seen: HashSet::new(),
+            order: VecDeque::new(),
+            capacity: None,
+            duplicates: 0,
+        }
+    }
+
+    /// Wraps `reader`, remembering only the `capacity` most recent distinct
+    /// `event_id`s.
+    pub fn with_capacity(reader: JournalReader, capacity: usize) -> Self {
+        Self {
+            capacity: Some(capacity),
+            ..Self::new(reader)
+        }
+    }
+
+    /// Returns the number of duplicate events skipped so far.
+    pub fn duplicates(&self) -> u64 {
+        self.duplicates
+    }
+
+    /// Returns the wrapped reader.
+    pub fn into_inner(self) -> JournalReader {
+        self.reader
+    }
+
+    /// Reads the next event not seen before.
+    ///
+    /// Returns `Ok(None)` when the wrapped reader does.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error [`JournalReader::read_event`] returns.
+    pub fn read_event(&mut self) -> Result<Option<EventJson>, JournalError> {
+        while let Some(event) = self.reader.read_event()? {
+            let Some(id) = event
+                .get("event_id")
+                .and_then(|id| id.get("b64"))
+                .and_then(|b64| b64.as_str())
+            else {
+                return Ok(Some(event));
+            };
+            if self.seen.contains(id) {
+                self.duplicates += 1;
+                continue;
+            }
+            if let Some(capacity) = self.capacity {
+                if self.order.len() == capacity {
+                    if let Some(oldest) = self.order.pop_front() {
+                        self.seen.remove(&oldest);
+                    } else {
+                        // A capacity of zero remembers nothing.
+                        return Ok(Some(event));
+                    }
+                }
+                self.order.push_back(id.to_string());
+            }
+            self.seen.insert(id.to_string());
+            return Ok(Some(event));
+        }
+        Ok(None)
+    }
+}
+
+/// Frame-level journal reader that yields raw payload bytes without parsing JSON.
+///
+/// Copy tools use this to move frames byte-for-byte, parsing a payload only
+/// when a decision requires it. Frames of every kind are yielded, including
+/// unknown kinds that [`JournalReader::read_event`] would skip. The returned
+/// payload slice borrows an internal buffer that is reused for each frame.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use northroot_journal::{FrameReader, JournalWriter, ReadMode, WriteOptions};
+///
+/// let mut frames = FrameReader::open("events.nrj", ReadMode::Strict)?;
+/// let mut writer = JournalWriter::open("copy.nrj", WriteOptions::default())?;
+/// while let Some((kind, payload)) = frames.next_frame()? {
+///     writer.append_raw(kind, payload)?;
+/// }
+/// writer.finish()?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct FrameReader {
+    reader: JournalReader,
+    payload: Vec<u8>,
+}
+
+impl FrameReader {
+    /// Opens a journal file for frame-level reading.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JournalError`] under the same conditions as [`JournalReader::open`].
+    pub fn open<P: AsRef<Path>>(path: P, mode: ReadMode) -> Result<Self, JournalError> {
+        Ok(Self {
+            reader: JournalReader::open(path, mode)?,
+            payload: Vec::new(),
+        })
+    }
+
+    /// Reads the next frame and returns its kind and exact payload bytes.
+    ///
+    /// Returns `Ok(None)` at end-of-file (or truncation in permissive mode).
+    pub fn next_frame(&mut self) -> Result<Option<(FrameKind, &[u8])>, JournalError> {
+        Ok(self
+            .reader
+            .read_frame_into(&mut self.payload)?
+            .map(|kind| (kind, self.payload.as_slice())))
+    }
+
+    /// Returns the byte offset of the next frame to be read.
+    pub fn position(&self) -> u64 {
+        self.reader.position()
+    }
 }
@@ -2,7 +2,7 @@
 
 use crate::errors::JournalError;
 use crate::event::{validate_event_object_structure, EventJson};
-use northroot_canonical::{compute_event_id, Canonicalizer};
+use northroot_canonical::{compute_event_id, Canonicalizer, Digest, Timestamp};
 
 /// Verifies an event JSON against its claimed event_id.
 ///
@@ -20,3 +20,209 @@ pub fn verify_event_id(
 
     Ok(claimed_id == computed_id)
 }
+
+/// A break in a journal's `prev_event_id` hash chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChainBreak {
+    /// Zero-based position of the event whose link is broken.
+    pub index: u64,
+    /// Digest the event should link to (`None` at the start of the chain).
+    pub expected: Option<Digest>,
+    /// Digest the event declares in `prev_event_id` (`None` when absent).
+    pub found: Option<Digest>,
+}
+
+/// Incremental checker for `prev_event_id` linkage in journal order.
+///
+/// The first event must omit `prev_event_id` (or name the configured genesis
+/// digest), and every later event must name the computed `event_id` of the
+/// event immediately before it. Links are checked against recomputed IDs, not
+/// claimed ones, so a tampered predecessor also breaks the chain.
+pub struct ChainVerifier<'a> {
+    canonicalizer: &'a Canonicalizer,
+    expected: Option<Digest>,
+    index: u64,
+}
+
+impl<'a> ChainVerifier<'a> {
+    /// Creates a checker for a chain whose first event has no `prev_event_id`.
+    pub fn new(canonicalizer: &'a Canonicalizer) -> Self {
+        Self {
+            canonicalizer,
+            expected: None,
+            index: 0,
+        }
+    }
+
+    /// Creates a checker for a chain whose first event links to `genesis`.
+    pub fn with_genesis(canonicalizer: &'a Canonicalizer, genesis: Digest) -> Self {
+        Self {
+            canonicalizer,
+            expected: Some(genesis),
+            index: 0,
+        }
+    }
+
+    /// Checks the next event's link and advances the chain.
+    ///
+    /// Returns the break when the event's `prev_event_id` does not match its
+    /// predecessor. Checking continues from the event's own computed ID, so
+    /// each broken link is reported once.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JournalError`] if `prev_event_id` is not digest-shaped or the
+    /// event ID cannot be computed.
+    pub fn check(&mut self, event: &EventJson) -> Result<Option<ChainBreak>, JournalError> {
+        let found = match event.get("prev_event_id") {
+            None | Some(serde_json::Value::Null) => None,
+            Some(value) => {
+                let digest: Digest = serde_json::from_value(value.clone()).map_err(|e| {
+                    JournalError::InvalidJson(format!("prev_event_id must be digest-shaped: {}", e))
+                })?;
+                Some(digest)
+            }
+        };
+        let computed = compute_event_id(event, self.canonicalizer).map_err(|e| {
+            JournalError::InvalidJson(format!("event ID computation failed: {}", e))
+        })?;
+
+        let expected = self.expected.replace(computed);
+        let index = self.index;
+        self.index += 1;
+
+        if found == expected {
+            Ok(None)
+        } else {
+            Ok(Some(ChainBreak {
+                index,
+                expected,
+                found,
+            }))
+        }
+    }
+}
+
+/// Verifies the `prev_event_id` hash chain across events in journal order.
+///
+/// Returns the first [`ChainBreak`], or `None` when every event links to its
+/// predecessor and the first event has no `prev_event_id`.
+///
+/// # Example
+///
+/// ```rust
+/// use northroot_canonical::{compute_event_id, Canonicalizer, ProfileId};
+/// use northroot_journal::verify_chain;
+/// use serde_json::json;
+///
+/// let canonicalizer = Canonicalizer::new(ProfileId::parse("northroot-canonical-v1")?);
+/// let first = json!({"event_type": "test", "event_version": "1"});
+/// let first_id = compute_event_id(&first, &canonicalizer)?;
+/// let second = json!({
+///     "event_type": "test",
+///     "event_version": "1",
+///     "prev_event_id": first_id
+/// });
+///
+/// assert!(verify_chain(&[first.clone(), second.clone()], &canonicalizer)?.is_none());
+/// let reordered = verify_chain(&[second, first], &canonicalizer)?.unwrap();
+/// assert_eq!(reordered.index, 0);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns [`JournalError`] if a `prev_event_id` is malformed or an event ID
+/// cannot be computed.
+pub fn verify_chain(
+    events: &[EventJson],
+    canonicalizer: &Canonicalizer,
+) -> Result<Option<ChainBreak>, JournalError> {
+    let mut chain = ChainVerifier::new(canonicalizer);
+    for event in events {
+        if let Some(chain_break) = chain.check(event)? {
+            return Ok(Some(chain_break));
+        }
+    }
+    Ok(None)
+}
+
+/// Why an event failed the `occurred_at` ordering check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderViolation {
+    /// `occurred_at` is missing or not a valid RFC3339 UTC timestamp.
+    InvalidTimestamp,
+    /// `occurred_at` precedes the previous event's by more than the tolerance.
+    Regressed {
+        /// How far, in milliseconds, the event is behind its predecessor.
+        by_ms: u64,
+    },
+}
+
+/// Incremental checker for non-decreasing `occurred_at` in journal order.
+///
+/// Each event is compared with the nearest earlier event that had a valid
+/// timestamp, allowing `tolerance_ms` of clock skew. Comparison uses
+/// millisecond resolution; sub-millisecond digits are ignored.
+pub struct TemporalOrderChecker {
+    tolerance_ms: u64,
+    previous: Option<i64>,
+}
+
+impl TemporalOrderChecker {
+    /// Creates a checker that tolerates regressions of up to `tolerance_ms`.
+    pub fn new(tolerance_ms: u64) -> Self {
+        Self {
+            tolerance_ms,
+            previous: None,
+        }
+    }
+
+    /// Checks the next event and advances to its timestamp.
+    pub fn check(&mut self, event: &EventJson) -> Option<OrderViolation> {
+        let Some(current) = event
+            .get("occurred_at")
+            .and_then(|value| serde_json::from_value::<Timestamp>(value.clone()).ok())
+            .and_then(|timestamp| timestamp.unix_millis())
+        else {
+            return Some(OrderViolation::InvalidTimestamp);
+        };
+
+        let previous = self.previous.replace(current)?;
+        let by_ms = previous.saturating_sub(current);
+        if by_ms > 0 && by_ms as u64 > self.tolerance_ms {
+            Some(OrderViolation::Regressed {
+                by_ms: by_ms as u64,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the indices of events whose `occurred_at` is out of order.
+///
+/// An index is reported when the event's `occurred_at` is missing or
+/// invalid, or precedes its predecessor's by more than `tolerance_ms`.
+///
+/// # Example
+///
+/// ```rust
+/// use northroot_journal::verify_temporal_order;
+/// use serde_json::json;
+///
+/// let events = [
+///     json!({"occurred_at": "2024-01-01T00:00:01Z"}),
+///     json!({"occurred_at": "2024-01-01T00:00:00.900Z"}),
+///     json!({"occurred_at": "2024-01-01T00:00:00Z"}),
+/// ];
+/// assert_eq!(verify_temporal_order(&events, 100), vec![2]);
+/// ```
+pub fn verify_temporal_order(events: &[EventJson], tolerance_ms: u64) -> Vec<usize> {
+    let mut checker = TemporalOrderChecker::new(tolerance_ms);
+    events
+        .iter()
+        .enumerate()
+        .filter_map(|(index, event)| checker.check(event).map(|_| index))
+        .collect()
+}
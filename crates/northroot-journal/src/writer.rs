@@ -1,11 +1,19 @@
 //! Journal writer implementation.
 
+use crate::compression;
 use crate::errors::JournalError;
 use crate::event::EventJson;
-use crate::frame::{FrameKind, JournalHeader, RecordFrame};
+use crate::footer::FooterState;
+use crate::frame::{
+    FrameKind, JournalHeader, RecordFrame, FLAG_COMPRESSED_FRAMES, FLAG_FRAME_CRC32C,
+};
+use crate::index::{build_index, load_index, JournalIndex};
+use crate::reader::{JournalReader, ReadMode};
+use northroot_canonical::{compute_event_id, Canonicalizer, Digest};
+use serde::Serialize;
 use std::fs::{File, OpenOptions};
 use std::io::{self, Read, Seek, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Options for journal writing.
 #[derive(Debug, Clone)]
@@ -16,6 +24,29 @@ pub struct WriteOptions {
     pub create: bool,
     /// Whether to append to an existing file (default: true).
     pub append: bool,
+    /// Whether [`JournalWriter::finish`] appends a footer frame (default: false).
+    pub write_footer: bool,
+    /// Whether a new journal's frames carry CRC32C trailers (default: false).
+    ///
+    /// Only applies when the writer creates the header; an existing journal
+    /// keeps the setting recorded in its header.
+    pub frame_crc: bool,
+    /// Whether [`JournalWriter::finish`] writes a [`JournalIndex`] sidecar
+    /// covering every event in the file (default: false).
+    pub index: bool,
+    /// Compress event payloads longer than this many bytes with zstd
+    /// (default: `None`, never compress).
+    ///
+    /// Compressed events are written as
+    /// [`FrameKind::EventJsonZstd`] frames, and only when compression makes
+    /// the payload smaller. Requires the `compression` cargo feature;
+    /// [`JournalWriter::open`] fails if it is set without it.
+    ///
+    /// A new journal's header then sets the compressed-frames flag, so
+    /// readers that predate the frame kind reject the journal instead of
+    /// skipping its compressed events. An existing journal can only be
+    /// written with compression if its header already has the flag.
+    pub compress: Option<usize>,
 }
 
 impl Default for WriteOptions {
@@ -24,6 +55,10 @@ impl Default for WriteOptions {
             sync: false,
             create: true,
             append: true,
+            write_footer: false,
+            frame_crc: false,
+            index: false,
+            compress: None,
         }
     }
 }
@@ -66,8 +101,13 @@ impl Default for WriteOptions {
 /// - [Journal Format Reference](../../../docs/reference/format.md) - Format specification
 pub struct JournalWriter {
     file: File,
+    path: PathBuf,
     sync: bool,
     header_written: bool,
+    frame_crc: bool,
+    footer: Option<FooterState>,
+    index: Option<JournalIndex>,
+    compress: Option<usize>,
 }
 
 impl JournalWriter {
@@ -77,6 +117,12 @@ impl JournalWriter {
     /// file is created with a header. If the file exists, it is opened for appending
     /// (if `options.append` is `true`) or truncated (if `false`).
     ///
+    /// With `options.write_footer`, the events already in an appended journal
+    /// are scanned so the footer written by [`finish`](Self::finish) covers
+    /// the whole file. With `options.index`, an appended journal's existing
+    /// sidecar is reused if it is readable and not stale, and rebuilt
+    /// otherwise.
+    ///
     /// # Example
     ///
     /// ```rust,no_run
@@ -86,6 +132,10 @@ impl JournalWriter {
     ///     sync: false,
     ///     create: true,
     ///     append: true,
+    ///     write_footer: false,
+    ///     frame_crc: false,
+    ///     index: false,
+    ///     compress: None,
     /// };
     /// let writer = JournalWriter::open("events.nrj", options)?;
     /// # Ok::<(), Box<dyn std::error::Error>>(())
@@ -94,20 +144,33 @@ impl JournalWriter {
     /// # Errors
     ///
     /// Returns [`JournalError`] if:
-    /// - File cannot be opened/created
+    /// - File cannot be opened/created ([`JournalError::Open`], naming the path)
     /// - Existing file is not a valid journal
     /// - File is not empty but too small to be valid
+    /// - `options.compress` is set without the `compression` feature, or for
+    ///   an existing journal whose header lacks the compressed-frames flag
     pub fn open<P: AsRef<Path>>(path: P, options: WriteOptions) -> Result<Self, JournalError> {
+        let path = path.as_ref();
+        #[cfg(not(feature = "compression"))]
+        if options.compress.is_some() {
+            return Err(compression::unavailable());
+        }
         let file = OpenOptions::new()
             .create(options.create)
             .write(true)
             .read(true)
-            .open(path)?;
+            .open(path)
+            .map_err(|e| JournalError::open(path, e))?;
 
         let mut writer = Self {
             file,
+            path: path.to_path_buf(),
             sync: options.sync,
             header_written: false,
+            frame_crc: options.frame_crc,
+            footer: options.write_footer.then(FooterState::new),
+            index: options.index.then(JournalIndex::new),
+            compress: options.compress,
         };
 
         // Check if file is empty; if so, write header
@@ -121,10 +184,14 @@ impl JournalWriter {
             let mut header_bytes = [0u8; JournalHeader::HEADER_SIZE];
             writer.file.seek(io::SeekFrom::Start(0))?;
             writer.file.read_exact(&mut header_bytes)?;
-            JournalHeader::from_bytes(&header_bytes)?;
+            let header = JournalHeader::from_bytes(&header_bytes)?;
+            check_compress_flag(&header, writer.compress)?;
+            writer.frame_crc = header.has_frame_crc();
             writer.header_written = true;
             // Seek to end for appending
             if options.append {
+                (writer.footer, writer.index) =
+                    existing_state(path, writer.footer.is_some(), writer.index.is_some())?;
                 writer.file.seek(io::SeekFrom::End(0))?;
             } else {
                 writer.file.seek(io::SeekFrom::Start(0))?;
@@ -139,8 +206,7 @@ impl JournalWriter {
     }
 
     fn write_header(&mut self) -> Result<(), JournalError> {
-        let header = JournalHeader::new();
-        let bytes = header.to_bytes();
+        let bytes = new_header(self.frame_crc, self.compress.is_some()).to_bytes();
         self.file.write_all(&bytes)?;
         self.file.flush()?;
         if self.sync {
@@ -179,46 +245,300 @@ impl JournalWriter {
     /// Returns [`JournalError`] if:
     /// - Header not written
     /// - JSON serialization fails
+    /// - Footers are enabled and the event has no digest-shaped `event_id`
     /// - I/O error occurs
     pub fn append_event(&mut self, event: &EventJson) -> Result<(), JournalError> {
         let json_bytes = serde_json::to_vec(event)?;
-        self.append_raw(FrameKind::EventJson, &json_bytes)
+        let footer = self.footer_after(event)?;
+        let (kind, payload) = encode_event(self.compress, json_bytes)?;
+        let offset = self.write_frame(kind, &payload)?;
+        self.footer = footer.or(self.footer.take());
+        if let Some(index) = self.index.as_mut() {
+            index.insert(event, offset);
+        }
+        Ok(())
+    }
+
+    /// Appends an event and returns its `event_id`.
+    ///
+    /// An event that already carries an `event_id` is written as-is and that
+    /// ID is returned without being recomputed; use
+    /// [`verify_event_id`](crate::verify_event_id) to check it. Otherwise the
+    /// ID is computed with `canonicalizer` and added to the written event.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use northroot_canonical::{Canonicalizer, ProfileId};
+    /// use northroot_journal::{JournalWriter, WriteOptions};
+    /// use serde_json::json;
+    ///
+    /// let canonicalizer = Canonicalizer::new(ProfileId::parse("northroot-canonical-v1")?);
+    /// let mut writer = JournalWriter::open("events.nrj", WriteOptions::default())?;
+    /// let event_id =
+    ///     writer.append_event_with_id(&json!({"event_type": "test"}), &canonicalizer)?;
+    /// println!("appended {}", event_id.b64);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JournalError`] if:
+    /// - The embedded `event_id` is not a valid digest
+    /// - The event_id cannot be computed
+    /// - Any condition of [`append_event`](Self::append_event) applies
+    pub fn append_event_with_id(
+        &mut self,
+        event: &EventJson,
+        canonicalizer: &Canonicalizer,
+    ) -> Result<Digest, JournalError> {
+        if let Some(event_id) = event.get("event_id") {
+            let event_id: Digest = serde_json::from_value(event_id.clone())?;
+            self.append_event(event)?;
+            return Ok(event_id);
+        }
+
+        let event_id = compute_event_id(event, canonicalizer)?;
+        let mut event = event.clone();
+        if let Some(object) = event.as_object_mut() {
+            object.insert("event_id".to_string(), serde_json::to_value(&event_id)?);
+        }
+        self.append_event(&event)?;
+        Ok(event_id)
+    }
+
+    /// Serializes `event` and appends it, returning its `event_id`.
+    ///
+    /// Lets callers append their own event structs without building
+    /// [`EventJson`] by hand. `event` must serialize to a JSON object. An
+    /// `event_id` that serializes to `null` (such as an unset `Option`) is
+    /// treated as absent; the event is then handled as by
+    /// [`append_event_with_id`](Self::append_event_with_id).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use northroot_canonical::{Canonicalizer, ProfileId};
+    /// use northroot_journal::{JournalWriter, WriteOptions};
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Heartbeat {
+    ///     event_type: &'static str,
+    ///     event_version: &'static str,
+    /// }
+    ///
+    /// let canonicalizer = Canonicalizer::new(ProfileId::parse("northroot-canonical-v1")?);
+    /// let mut writer = JournalWriter::open("events.nrj", WriteOptions::default())?;
+    /// let event = Heartbeat { event_type: "heartbeat", event_version: "1" };
+    /// let event_id = writer.append_typed(&event, &canonicalizer)?;
+    /// println!("appended {}", event_id.b64);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JournalError`] if:
+    /// - `event` does not serialize to a JSON object
+    /// - Any condition of [`append_event_with_id`](Self::append_event_with_id) applies
+    pub fn append_typed<T: Serialize>(
+        &mut self,
+        event: &T,
+        canonicalizer: &Canonicalizer,
+    ) -> Result<Digest, JournalError> {
+        let mut event = serde_json::to_value(event)?;
+        let Some(object) = event.as_object_mut() else {
+            return Err(JournalError::InvalidJson(
+                "event must serialize to a JSON object".to_string(),
+            ));
+        };
+        if object.get("event_id").is_some_and(|id| id.is_null()) {
+            object.remove("event_id");
+        }
+        self.append_event_with_id(&event, canonicalizer)
     }
 
     /// Appends a raw frame with the given kind and payload.
+    ///
+    /// When footers or the index are enabled, event payloads are parsed
+    /// (and decompressed) so the footer and index still cover them.
     pub fn append_raw(&mut self, kind: FrameKind, payload: &[u8]) -> Result<(), JournalError> {
+        let event: Option<EventJson> =
+            if kind.is_event() && (self.footer.is_some() || self.index.is_some()) {
+                Some(serde_json::from_slice(&compression::event_payload(
+                    kind, payload,
+                )?)?)
+            } else {
+                None
+            };
+        let footer = match &event {
+            Some(event) => self.footer_after(event)?,
+            None => None,
+        };
+        let offset = self.write_frame(kind, payload)?;
+        self.footer = footer.or(self.footer.take());
+        if let (Some(index), Some(event)) = (self.index.as_mut(), &event) {
+            index.insert(event, offset);
+        }
+        Ok(())
+    }
+
+    /// Returns the footer state after `event`, without committing it.
+    fn footer_after(&self, event: &EventJson) -> Result<Option<FooterState>, JournalError> {
+        footer_after(self.footer.as_ref(), event)
+    }
+
+    /// Writes a frame and returns the offset it starts at.
+    fn write_frame(&mut self, kind: FrameKind, payload: &[u8]) -> Result<u64, JournalError> {
         if !self.header_written {
             return Err(JournalError::InvalidHeader(
                 "header not written".to_string(),
             ));
         }
 
-        let frame = RecordFrame::new(kind, payload.len() as u32)?;
-        let frame_bytes = frame.to_bytes();
-
-        // Write frame header
+        let frame_bytes = encode_frame(kind, payload, self.frame_crc)?;
+        let offset = self.file.stream_position()?;
         self.file.write_all(&frame_bytes)?;
-        // Write payload
-        self.file.write_all(payload)?;
         self.file.flush()?;
 
         if self.sync {
             self.file.sync_all()?;
         }
 
-        Ok(())
+        Ok(offset)
     }
 
     /// Finishes writing and closes the file.
+    ///
+    /// With [`WriteOptions::write_footer`], a [`JournalFooter`](crate::JournalFooter)
+    /// summarizing every event in the file is appended first. A writer that is
+    /// dropped without calling `finish` leaves no footer, so readers report the
+    /// journal as incomplete.
+    ///
+    /// With [`WriteOptions::index`], the [`JournalIndex`] sidecar is then
+    /// written, stamped with the journal's final size and modification time.
     pub fn finish(mut self) -> Result<(), JournalError> {
+        if let Some(footer) = self.footer.take() {
+            let payload = footer.footer().to_bytes()?;
+            self.write_frame(FrameKind::Footer, &payload)?;
+        }
         self.file.flush()?;
         if self.sync {
             self.file.sync_all()?;
         }
+        if let Some(mut index) = self.index.take() {
+            index.stamp(&self.path)?;
+            index.save(&self.path)?;
+        }
         Ok(())
     }
 }
 
+/// Returns the header a writer creates a new journal with.
+pub(crate) fn new_header(frame_crc: bool, compress: bool) -> JournalHeader {
+    let mut header = JournalHeader::new();
+    if frame_crc {
+        header.flags |= FLAG_FRAME_CRC32C;
+    }
+    if compress {
+        header.flags |= FLAG_COMPRESSED_FRAMES;
+    }
+    header
+}
+
+/// Refuses compression for an existing journal whose header does not allow
+/// compressed frames, which older readers would otherwise skip silently.
+pub(crate) fn check_compress_flag(
+    header: &JournalHeader,
+    compress: Option<usize>,
+) -> Result<(), JournalError> {
+    if compress.is_some() && !header.has_compressed_frames() {
+        return Err(JournalError::Compression(
+            "journal header does not allow compressed frames".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the footer and index state covering the events already in the
+/// journal at `path`, for the parts that are enabled.
+pub(crate) fn existing_state(
+    path: &Path,
+    footer: bool,
+    index: bool,
+) -> Result<(Option<FooterState>, Option<JournalIndex>), JournalError> {
+    let footer = if footer {
+        let mut reader = JournalReader::open(path, ReadMode::Strict)?;
+        while reader.read_event()?.is_some() {}
+        Some(reader.footer_state().clone())
+    } else {
+        None
+    };
+    let index = if index {
+        // The sidecar is derived data: rebuild it rather than fail if it is
+        // corrupt or from another version.
+        Some(match load_index(path) {
+            Ok(Some(index)) if !index.is_stale(path)? => index,
+            Ok(_) | Err(JournalError::InvalidIndex(_)) => build_index(path)?,
+            Err(e) => return Err(e),
+        })
+    } else {
+        None
+    };
+    Ok((footer, index))
+}
+
+/// Chooses the frame kind and payload for serialized event JSON,
+/// compressing it when [`WriteOptions::compress`] applies and helps.
+pub(crate) fn encode_event(
+    compress: Option<usize>,
+    json_bytes: Vec<u8>,
+) -> Result<(FrameKind, Vec<u8>), JournalError> {
+    match compress {
+        Some(threshold) if json_bytes.len() > threshold => {
+            let compressed = compression::compress(&json_bytes)?;
+            if compressed.len() < json_bytes.len() {
+                return Ok((FrameKind::EventJsonZstd, compressed));
+            }
+            Ok((FrameKind::EventJson, json_bytes))
+        }
+        _ => Ok((FrameKind::EventJson, json_bytes)),
+    }
+}
+
+/// Returns `footer` advanced past `event`, without committing it.
+pub(crate) fn footer_after(
+    footer: Option<&FooterState>,
+    event: &EventJson,
+) -> Result<Option<FooterState>, JournalError> {
+    let Some(footer) = footer else {
+        return Ok(None);
+    };
+    let mut next = footer.clone();
+    if !next.record(event) {
+        return Err(JournalError::InvalidJson(
+            "footer requires a digest-shaped event_id".to_string(),
+        ));
+    }
+    Ok(Some(next))
+}
+
+/// Serializes a frame: header, payload, and CRC32C trailer if enabled.
+pub(crate) fn encode_frame(
+    kind: FrameKind,
+    payload: &[u8],
+    frame_crc: bool,
+) -> Result<Vec<u8>, JournalError> {
+    let frame = RecordFrame::new(kind, payload.len() as u32)?;
+    let mut bytes = Vec::with_capacity(RecordFrame::FRAME_HEADER_SIZE + payload.len() + 4);
+    bytes.extend_from_slice(&frame.to_bytes());
+    bytes.extend_from_slice(payload);
+    if frame_crc {
+        bytes.extend_from_slice(&crc32c::crc32c(payload).to_le_bytes());
+    }
+    Ok(bytes)
+}
+
 impl Drop for JournalWriter {
     fn drop(&mut self) {
         let _ = self.file.flush();
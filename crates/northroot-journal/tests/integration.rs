@@ -2,9 +2,11 @@
 // Miri's filesystem emulation is slow and doesn't provide additional UB detection
 // beyond normal test runs. Skip under Miri; core frame logic is tested in frame.rs.
 
-use northroot_canonical::{verify_event_id, Canonicalizer, Digest, ProfileId};
+use northroot_canonical::{compute_blob_digest, verify_event_id, Canonicalizer, Digest, ProfileId};
 use northroot_journal::{
-    EventJson, FrameKind, JournalReader, JournalWriter, ReadMode, WriteOptions,
+    build_index, load_index, ChainVerifier, ChainedReader, DedupReader, EventJson, FrameKind,
+    FrameReader, JournalError, JournalHeader, JournalIndex, JournalReader, JournalWriter, ReadMode,
+    ReverseJournalReader, TypeCounts, WriteOptions,
 };
 use serde_json::json;
 use std::fs;
@@ -59,6 +61,51 @@ fn test_write_read_round_trip() {
     }
 }
 
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_events_iterator_adapters() {
+    let temp_dir = TempDir::new().unwrap();
+    let journal_path = temp_dir.path().join("test.nrj");
+    let mut writer = JournalWriter::open(&journal_path, WriteOptions::default()).unwrap();
+    for id in ["e1", "e2", "e3", "e4"] {
+        writer.append_event(&make_test_event(id)).unwrap();
+    }
+    writer.finish().unwrap();
+    let open = || JournalReader::open(&journal_path, ReadMode::Strict).unwrap();
+
+    let ids: Vec<_> = open()
+        .events()
+        .map(|event| event.unwrap()["event_id"]["b64"].clone())
+        .filter(|id| id != "e2")
+        .take(2)
+        .collect();
+    assert_eq!(ids, ["e1", "e3"]);
+    assert_eq!(
+        open()
+            .events()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+            .len(),
+        4
+    );
+
+    // Iteration stops after the first error.
+    let len = fs::metadata(&journal_path).unwrap().len();
+    fs::OpenOptions::new()
+        .write(true)
+        .open(&journal_path)
+        .unwrap()
+        .set_len(len - 3)
+        .unwrap();
+    let results: Vec<_> = open().events().collect();
+    assert_eq!(results.len(), 4);
+    assert!(results[..3].iter().all(Result::is_ok));
+    assert!(matches!(
+        results[3],
+        Err(JournalError::TruncatedFrame { .. })
+    ));
+}
+
 #[test]
 #[cfg_attr(miri, ignore)]
 fn test_append_to_existing() {
@@ -161,6 +208,36 @@ fn test_invalid_header_rejected() {
     assert!(JournalReader::open(&journal_path, ReadMode::Strict).is_err());
 }
 
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_open_error_names_missing_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let journal_path = temp_dir.path().join("missing.nrj");
+
+    let Err(err) = JournalReader::open(&journal_path, ReadMode::Strict) else {
+        panic!("opening a missing journal should fail");
+    };
+    let message = err.to_string();
+    assert!(message.contains("missing.nrj"), "{}", message);
+    assert!(!message.contains(&*temp_dir.path().to_string_lossy()));
+    match err {
+        JournalError::Open { path, source } => {
+            assert_eq!(path, journal_path);
+            assert_eq!(source.kind(), std::io::ErrorKind::NotFound);
+        }
+        other => panic!("expected Open error, got {:?}", other),
+    }
+
+    let options = WriteOptions {
+        create: false,
+        ..WriteOptions::default()
+    };
+    assert!(matches!(
+        JournalWriter::open(&journal_path, options),
+        Err(JournalError::Open { .. })
+    ));
+}
+
 #[test]
 #[cfg_attr(miri, ignore)]
 fn test_empty_file_creates_header() {
@@ -226,3 +303,808 @@ fn test_checked_in_nrj_fixtures_are_readable_and_verifiable() {
     assert!(verify_event_id(&event, &claimed_id, &canonicalizer).unwrap());
     assert!(reader.read_event().unwrap().is_none());
 }
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_frame_reader_copies_frames_byte_for_byte() {
+    let temp_dir = TempDir::new().unwrap();
+    let source_path = temp_dir.path().join("source.nrj");
+    let copy_path = temp_dir.path().join("copy.nrj");
+
+    {
+        let mut writer = JournalWriter::open(&source_path, WriteOptions::default()).unwrap();
+        writer.append_event(&make_test_event("event1")).unwrap();
+        // Non-canonical spacing must survive the copy untouched.
+        writer
+            .append_raw(FrameKind::EventJson, br#"{ "event_id" : "spaced" }"#)
+            .unwrap();
+        writer
+            .append_raw(FrameKind::Unknown(0x7f), b"opaque")
+            .unwrap();
+        writer.append_event(&make_test_event("event2")).unwrap();
+        writer.finish().unwrap();
+    }
+
+    {
+        let mut frames = FrameReader::open(&source_path, ReadMode::Strict).unwrap();
+        let mut writer = JournalWriter::open(&copy_path, WriteOptions::default()).unwrap();
+        let mut kinds = Vec::new();
+        while let Some((kind, payload)) = frames.next_frame().unwrap() {
+            kinds.push(kind);
+            writer.append_raw(kind, payload).unwrap();
+        }
+        writer.finish().unwrap();
+
+        assert_eq!(
+            kinds,
+            vec![
+                FrameKind::EventJson,
+                FrameKind::EventJson,
+                FrameKind::Unknown(0x7f),
+                FrameKind::EventJson
+            ]
+        );
+        assert_eq!(frames.position(), fs::metadata(&source_path).unwrap().len());
+    }
+
+    assert_eq!(
+        fs::read(&source_path).unwrap(),
+        fs::read(&copy_path).unwrap()
+    );
+}
+
+fn write_footed_journal(path: &std::path::Path, names: &[&str]) -> Vec<EventJson> {
+    let options = WriteOptions {
+        write_footer: true,
+        ..Default::default()
+    };
+    let mut writer = JournalWriter::open(path, options).unwrap();
+    let events: Vec<EventJson> = names
+        .iter()
+        .map(|name| make_test_event(&compute_blob_digest(name.as_bytes()).unwrap().b64))
+        .collect();
+    for event in &events {
+        writer.append_event(event).unwrap();
+    }
+    writer.finish().unwrap();
+    events
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_footer_matches_written_events() {
+    let temp_dir = TempDir::new().unwrap();
+    let journal_path = temp_dir.path().join("footed.nrj");
+    let events = write_footed_journal(&journal_path, &["a", "b", "c"]);
+
+    let mut reader = JournalReader::open(&journal_path, ReadMode::Strict).unwrap();
+    let mut read = 0;
+    while reader.read_event().unwrap().is_some() {
+        read += 1;
+        assert!(!reader.is_complete());
+    }
+
+    assert_eq!(read, 3);
+    assert!(reader.is_complete());
+    let footer = reader.last_footer().unwrap();
+    assert_eq!(footer.event_count, 3);
+    assert_eq!(
+        footer.tip_event_id.as_ref().unwrap().b64,
+        events[2]["event_id"]["b64"]
+    );
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_trailing_footer_read_without_scanning() {
+    let temp_dir = TempDir::new().unwrap();
+    let journal_path = temp_dir.path().join("footed.nrj");
+    let events = write_footed_journal(&journal_path, &["a", "b", "c"]);
+
+    let reader = JournalReader::open(&journal_path, ReadMode::Strict).unwrap();
+    let footer = reader.footer().unwrap().unwrap();
+    assert_eq!(footer.event_count, events.len() as u64);
+    assert_eq!(
+        footer.tip_event_id.as_ref().unwrap().b64,
+        events[2]["event_id"]["b64"]
+    );
+    assert_eq!(reader.position(), JournalHeader::HEADER_SIZE as u64);
+
+    // Appending without a footer leaves an event as the last frame.
+    let mut writer = JournalWriter::open(&journal_path, WriteOptions::default()).unwrap();
+    writer.append_event(&make_test_event("late")).unwrap();
+    writer.finish().unwrap();
+    let reader = JournalReader::open(&journal_path, ReadMode::Strict).unwrap();
+    assert!(reader.footer().unwrap().is_none());
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_footer_covers_events_from_earlier_sessions() {
+    let temp_dir = TempDir::new().unwrap();
+    let journal_path = temp_dir.path().join("footed.nrj");
+    write_footed_journal(&journal_path, &["a", "b"]);
+    write_footed_journal(&journal_path, &["c"]);
+
+    let mut reader = JournalReader::open(&journal_path, ReadMode::Strict).unwrap();
+    while reader.read_event().unwrap().is_some() {}
+
+    assert!(reader.is_complete());
+    assert_eq!(reader.last_footer().unwrap().event_count, 3);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_truncation_before_footer_is_incomplete() {
+    let temp_dir = TempDir::new().unwrap();
+    let journal_path = temp_dir.path().join("footed.nrj");
+    write_footed_journal(&journal_path, &["a", "b"]);
+
+    // Drop the footer frame (8-byte header + 72-byte payload).
+    let len = fs::metadata(&journal_path).unwrap().len();
+    let file = fs::OpenOptions::new()
+        .write(true)
+        .open(&journal_path)
+        .unwrap();
+    file.set_len(len - 80).unwrap();
+
+    let mut reader = JournalReader::open(&journal_path, ReadMode::Strict).unwrap();
+    let mut read = 0;
+    while reader.read_event().unwrap().is_some() {
+        read += 1;
+    }
+
+    assert_eq!(read, 2);
+    assert!(!reader.is_complete());
+    assert!(reader.last_footer().is_none());
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_footer_mismatch_is_rejected() {
+    let temp_dir = TempDir::new().unwrap();
+    let journal_path = temp_dir.path().join("footed.nrj");
+    let source_path = temp_dir.path().join("source.nrj");
+    write_footed_journal(&source_path, &["a", "b"]);
+
+    // Copy frames but drop the first event, keeping the original footer.
+    let mut frames = FrameReader::open(&source_path, ReadMode::Strict).unwrap();
+    let mut writer = JournalWriter::open(&journal_path, WriteOptions::default()).unwrap();
+    frames.next_frame().unwrap();
+    while let Some((kind, payload)) = frames.next_frame().unwrap() {
+        writer.append_raw(kind, payload).unwrap();
+    }
+    writer.finish().unwrap();
+
+    let mut reader = JournalReader::open(&journal_path, ReadMode::Strict).unwrap();
+    assert!(reader.read_event().unwrap().is_some());
+    let err = reader.read_event().unwrap_err();
+    assert!(matches!(err, JournalError::FooterMismatch { .. }));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_reverse_reader_yields_newest_first() {
+    let temp_dir = TempDir::new().unwrap();
+    let journal_path = temp_dir.path().join("test.nrj");
+    let mut writer = JournalWriter::open(&journal_path, WriteOptions::default()).unwrap();
+    for i in 1..=5 {
+        writer
+            .append_event(&make_test_event(&format!("event{}", i)))
+            .unwrap();
+    }
+    writer.finish().unwrap();
+
+    let mut reader = ReverseJournalReader::open(&journal_path, ReadMode::Strict).unwrap();
+    assert_eq!(reader.remaining(), 5);
+    for i in (1..=5).rev() {
+        let event = reader.read_prev().unwrap().unwrap();
+        assert_eq!(event["event_id"]["b64"], format!("event{}", i));
+    }
+    assert!(reader.read_prev().unwrap().is_none());
+
+    // Cut into the last event: permissive mode drops the partial frame,
+    // strict mode refuses to open.
+    let file_size = fs::metadata(&journal_path).unwrap().len();
+    let file = fs::OpenOptions::new()
+        .write(true)
+        .open(&journal_path)
+        .unwrap();
+    file.set_len(file_size - 5).unwrap();
+    drop(file);
+
+    let mut reader = ReverseJournalReader::open(&journal_path, ReadMode::Permissive).unwrap();
+    let event = reader.read_prev().unwrap().unwrap();
+    assert_eq!(event["event_id"]["b64"], "event4");
+    assert_eq!(reader.remaining(), 3);
+    assert!(ReverseJournalReader::open(&journal_path, ReadMode::Strict).is_err());
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_read_event_with_offset_locates_frames() {
+    let temp_dir = TempDir::new().unwrap();
+    let journal_path = temp_dir.path().join("test.nrj");
+    let options = WriteOptions {
+        frame_crc: true,
+        ..WriteOptions::default()
+    };
+    let mut writer = JournalWriter::open(&journal_path, options).unwrap();
+    for i in 1..=3 {
+        writer
+            .append_event(&make_test_event(&format!("event{}", i)))
+            .unwrap();
+    }
+    writer.finish().unwrap();
+    let bytes = fs::read(&journal_path).unwrap();
+
+    let mut reader = JournalReader::open(&journal_path, ReadMode::Strict).unwrap();
+    let mut lookup = JournalReader::open(&journal_path, ReadMode::Strict).unwrap();
+    let mut next_offset = JournalHeader::HEADER_SIZE as u64;
+    while let Some((event, offset, length)) = reader.read_event_with_offset().unwrap() {
+        assert_eq!(offset, next_offset);
+        assert_eq!(lookup.read_at_offset(offset).unwrap(), Some(event.clone()));
+
+        // The frame's bytes alone, behind a header, decode to the same event.
+        let start = offset as usize;
+        let single_path = temp_dir.path().join("single.nrj");
+        let mut single = bytes[..JournalHeader::HEADER_SIZE].to_vec();
+        single.extend_from_slice(&bytes[start..start + length as usize]);
+        fs::write(&single_path, single).unwrap();
+        let mut single = JournalReader::open(&single_path, ReadMode::Strict).unwrap();
+        assert_eq!(single.read_event().unwrap(), Some(event));
+        assert_eq!(single.read_event().unwrap(), None);
+
+        next_offset = offset + u64::from(length);
+    }
+    assert_eq!(next_offset, reader.position());
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_index_lookups_match_linear_scan() {
+    let temp_dir = TempDir::new().unwrap();
+    let journal_path = temp_dir.path().join("test.nrj");
+    let mut writer = JournalWriter::open(&journal_path, WriteOptions::default()).unwrap();
+    for i in 1..=4 {
+        writer
+            .append_event(&make_test_event(&format!("event{}", i)))
+            .unwrap();
+    }
+    writer.append_event(&make_test_event("event1")).unwrap();
+    writer.finish().unwrap();
+
+    assert!(load_index(&journal_path).unwrap().is_none());
+    let index = build_index(&journal_path).unwrap();
+    index.save(&journal_path).unwrap();
+    assert!(JournalIndex::sidecar_path(&journal_path).ends_with("test.nrj.idx"));
+    assert_eq!(load_index(&journal_path).unwrap(), Some(index.clone()));
+    assert_eq!(index.len(), 4);
+
+    let mut scan = JournalReader::open(&journal_path, ReadMode::Strict).unwrap();
+    let mut lookup = JournalReader::open(&journal_path, ReadMode::Strict).unwrap();
+    let mut first_seen = std::collections::HashSet::new();
+    loop {
+        let offset = scan.position();
+        let Some(event) = scan.read_event().unwrap() else {
+            break;
+        };
+        let event_id = event["event_id"]["b64"].as_str().unwrap();
+        if first_seen.insert(event_id.to_string()) {
+            assert_eq!(index.get(event_id), Some(offset));
+        }
+        let found = lookup.read_at_offset(index.get(event_id).unwrap()).unwrap();
+        assert_eq!(found.as_ref(), Some(&event));
+    }
+    assert_eq!(index.get("missing"), None);
+    assert_eq!(lookup.position(), 16);
+
+    // An offset that is not a frame boundary is rejected.
+    assert!(lookup
+        .read_at_offset(index.get("event2").unwrap() + 1)
+        .is_err());
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_load_index_rejects_malformed_sidecar() {
+    let temp_dir = TempDir::new().unwrap();
+    let journal_path = temp_dir.path().join("test.nrj");
+    fs::write(JournalIndex::sidecar_path(&journal_path), b"not json").unwrap();
+
+    assert!(matches!(
+        load_index(&journal_path),
+        Err(JournalError::InvalidIndex(_))
+    ));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_writer_maintains_index_sidecar() {
+    let temp_dir = TempDir::new().unwrap();
+    let journal_path = temp_dir.path().join("test.nrj");
+    let indexed = WriteOptions {
+        index: true,
+        ..WriteOptions::default()
+    };
+    let append = |options: &WriteOptions, ids: &[&str]| {
+        let mut writer = JournalWriter::open(&journal_path, options.clone()).unwrap();
+        for id in ids {
+            writer.append_event(&make_test_event(id)).unwrap();
+        }
+        writer.finish().unwrap();
+    };
+
+    append(&indexed, &["event1", "event2"]);
+    append(&indexed, &["event3"]);
+    let index = load_index(&journal_path).unwrap().unwrap();
+    assert!(!index.is_stale(&journal_path).unwrap());
+    let scanned = build_index(&journal_path).unwrap();
+    assert_eq!(index.len(), 3);
+    for id in ["event1", "event2", "event3"] {
+        assert!(index.get(id).is_some());
+        assert_eq!(index.get(id), scanned.get(id));
+    }
+
+    // Appending without the option leaves the sidecar stale...
+    append(&WriteOptions::default(), &["event4"]);
+    let stale = load_index(&journal_path).unwrap().unwrap();
+    assert!(stale.is_stale(&journal_path).unwrap());
+    assert_eq!(stale.get("event4"), None);
+
+    // ...and the next indexed writer rebuilds it.
+    append(&indexed, &["event5"]);
+    let index = load_index(&journal_path).unwrap().unwrap();
+    assert!(!index.is_stale(&journal_path).unwrap());
+    assert_eq!(index.len(), 5);
+    assert_eq!(
+        index.get("event4"),
+        build_index(&journal_path).unwrap().get("event4")
+    );
+
+    // A corrupt sidecar is rebuilt rather than failing the open.
+    fs::write(JournalIndex::sidecar_path(&journal_path), b"garbage").unwrap();
+    append(&indexed, &["event6"]);
+    let index = load_index(&journal_path).unwrap().unwrap();
+    assert!(!index.is_stale(&journal_path).unwrap());
+    assert_eq!(index.len(), 6);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_append_event_with_id_returns_written_id() {
+    let temp_dir = TempDir::new().unwrap();
+    let journal_path = temp_dir.path().join("test.nrj");
+    let canonicalizer = Canonicalizer::new(ProfileId::parse("northroot-canonical-v1").unwrap());
+
+    let mut writer = JournalWriter::open(&journal_path, WriteOptions::default()).unwrap();
+    let unsigned = json!({"event_type": "test", "event_version": "1"});
+    let computed = writer
+        .append_event_with_id(&unsigned, &canonicalizer)
+        .unwrap();
+    let embedded = writer
+        .append_event_with_id(&make_test_event("event2"), &canonicalizer)
+        .unwrap();
+    writer.finish().unwrap();
+
+    let mut reader = JournalReader::open(&journal_path, ReadMode::Strict).unwrap();
+    let first = reader.read_event().unwrap().unwrap();
+    assert_eq!(first["event_id"], serde_json::to_value(&computed).unwrap());
+    assert!(verify_event_id(&first, &computed, &canonicalizer).unwrap());
+    let second = reader.read_event().unwrap().unwrap();
+    assert_eq!(embedded.b64, "event2");
+    assert_eq!(second["event_id"]["b64"], embedded.b64);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_append_typed_round_trips_struct() {
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Heartbeat {
+        event_type: String,
+        event_version: String,
+        occurred_at: String,
+        event_id: Option<Digest>,
+        sequence: u64,
+    }
+
+    let temp_dir = TempDir::new().unwrap();
+    let journal_path = temp_dir.path().join("typed.nrj");
+    let canonicalizer = Canonicalizer::new(ProfileId::parse("northroot-canonical-v1").unwrap());
+    let heartbeat = Heartbeat {
+        event_type: "heartbeat".to_string(),
+        event_version: "1".to_string(),
+        occurred_at: "2024-01-01T00:00:00Z".to_string(),
+        event_id: None,
+        sequence: 7,
+    };
+
+    let mut writer = JournalWriter::open(&journal_path, WriteOptions::default()).unwrap();
+    let event_id = writer.append_typed(&heartbeat, &canonicalizer).unwrap();
+    let err = writer.append_typed(&"not an object", &canonicalizer);
+    assert!(matches!(err, Err(JournalError::InvalidJson(_))));
+    writer.finish().unwrap();
+
+    let mut reader = JournalReader::open(&journal_path, ReadMode::Strict).unwrap();
+    let event = reader.read_event().unwrap().unwrap();
+    assert!(verify_event_id(&event, &event_id, &canonicalizer).unwrap());
+    let read: Heartbeat = serde_json::from_value(event).unwrap();
+    assert_eq!(
+        read,
+        Heartbeat {
+            event_id: Some(event_id),
+            ..heartbeat
+        }
+    );
+    assert!(reader.read_event().unwrap().is_none());
+}
+
+#[test]
+#[cfg(feature = "compression")]
+#[cfg_attr(miri, ignore)]
+fn test_compressed_frames_round_trip() {
+    let temp_dir = TempDir::new().unwrap();
+    let plain_path = temp_dir.path().join("plain.nrj");
+    let compressed_path = temp_dir.path().join("compressed.nrj");
+
+    let mut large = make_test_event("large");
+    large["note"] = json!("repetitive payload ".repeat(200));
+    let small = make_test_event("small");
+    let events = [large.clone(), small, large];
+
+    for (path, compress) in [(&plain_path, None), (&compressed_path, Some(1024))] {
+        let options = WriteOptions {
+            compress,
+            ..WriteOptions::default()
+        };
+        let mut writer = JournalWriter::open(path, options).unwrap();
+        for event in &events {
+            writer.append_event(event).unwrap();
+        }
+        writer.finish().unwrap();
+    }
+
+    // Events above the threshold are compressed; the small one is not.
+    let mut frames = FrameReader::open(&compressed_path, ReadMode::Strict).unwrap();
+    let mut kinds = Vec::new();
+    while let Some((kind, _)) = frames.next_frame().unwrap() {
+        kinds.push(kind);
+    }
+    assert_eq!(
+        kinds,
+        [
+            FrameKind::EventJsonZstd,
+            FrameKind::EventJson,
+            FrameKind::EventJsonZstd
+        ]
+    );
+
+    let mut reader = JournalReader::open(&compressed_path, ReadMode::Strict).unwrap();
+    for event in &events {
+        assert_eq!(&reader.read_event().unwrap().unwrap(), event);
+    }
+    assert!(reader.read_event().unwrap().is_none());
+
+    let plain_len = fs::metadata(&plain_path).unwrap().len();
+    let compressed_len = fs::metadata(&compressed_path).unwrap().len();
+    assert!(
+        compressed_len * 4 < plain_len,
+        "{compressed_len} vs {plain_len}"
+    );
+
+    // The header announces compressed frames, so older readers reject it.
+    let header = |path| JournalHeader::from_bytes(&fs::read(path).unwrap()).unwrap();
+    assert!(header(&compressed_path).has_compressed_frames());
+    assert!(!header(&plain_path).has_compressed_frames());
+
+    // Compression can only be added to a journal created with it.
+    let options = WriteOptions {
+        compress: Some(1024),
+        ..WriteOptions::default()
+    };
+    assert!(matches!(
+        JournalWriter::open(&plain_path, options.clone()),
+        Err(JournalError::Compression(_))
+    ));
+    let mut writer = JournalWriter::open(&compressed_path, options).unwrap();
+    writer.append_event(&events[0]).unwrap();
+    writer.finish().unwrap();
+}
+
+#[test]
+#[cfg(not(feature = "compression"))]
+#[cfg_attr(miri, ignore)]
+fn test_compress_option_requires_feature() {
+    let temp_dir = TempDir::new().unwrap();
+    let options = WriteOptions {
+        compress: Some(0),
+        ..WriteOptions::default()
+    };
+    assert!(matches!(
+        JournalWriter::open(temp_dir.path().join("test.nrj"), options),
+        Err(JournalError::Compression(_))
+    ));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_chained_reader_streams_rotated_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let canonicalizer = Canonicalizer::new(ProfileId::parse("northroot-canonical-v1").unwrap());
+    let mut events = Vec::new();
+    let mut prev: Option<Digest> = None;
+    for seq in 0..6 {
+        let mut event = json!({
+            "event_type": "test",
+            "event_version": "1",
+            "seq": seq
+        });
+        if let Some(prev) = &prev {
+            event["prev_event_id"] = serde_json::to_value(prev).unwrap();
+        }
+        let event_id = northroot_canonical::compute_event_id(&event, &canonicalizer).unwrap();
+        event["event_id"] = serde_json::to_value(&event_id).unwrap();
+        prev = Some(event_id);
+        events.push(event);
+    }
+
+    let paths: Vec<_> = (1..=3)
+        .map(|n| temp_dir.path().join(format!("events-{:03}.nrj", n)))
+        .collect();
+    for (path, chunk) in paths.iter().zip(events.chunks(2)) {
+        let mut writer = JournalWriter::open(path, WriteOptions::default()).unwrap();
+        for event in chunk {
+            writer.append_event(event).unwrap();
+        }
+        writer.finish().unwrap();
+    }
+
+    let mut reader = ChainedReader::open(&paths, ReadMode::Strict).unwrap();
+    let mut chain = ChainVerifier::new(&canonicalizer);
+    let mut read = Vec::new();
+    while let Some(event) = reader.read_event().unwrap() {
+        assert_eq!(chain.check(&event).unwrap(), None);
+        read.push(event);
+    }
+    assert_eq!(read, events);
+    assert_eq!(reader.current_file(), 2);
+
+    // Headers are validated up front.
+    fs::write(&paths[2], b"not a journal").unwrap();
+    assert!(ChainedReader::open(&paths, ReadMode::Strict).is_err());
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_count_and_type_scans_match_full_parse() {
+    let temp_dir = TempDir::new().unwrap();
+    let journal_path = temp_dir.path().join("counts.nrj");
+    let options = WriteOptions {
+        write_footer: true,
+        frame_crc: true,
+        ..WriteOptions::default()
+    };
+
+    let mut writer = JournalWriter::open(&journal_path, options).unwrap();
+    for (i, event_type) in ["authorization", "execution", "execution", "checkpoint"]
+        .iter()
+        .enumerate()
+    {
+        let mut event = make_test_event(&compute_blob_digest(&[i as u8]).unwrap().b64);
+        event["event_type"] = json!(event_type);
+        writer.append_event(&event).unwrap();
+    }
+    let mut untyped = make_test_event(&compute_blob_digest(b"untyped").unwrap().b64);
+    untyped["event_type"] = json!(7);
+    writer.append_event(&untyped).unwrap();
+    writer
+        .append_raw(FrameKind::Unknown(0x7f), b"opaque")
+        .unwrap();
+    writer.finish().unwrap();
+
+    let mut reader = JournalReader::open(&journal_path, ReadMode::Strict).unwrap();
+    let mut full = TypeCounts::default();
+    while let Some(event) = reader.read_event().unwrap() {
+        full.events += 1;
+        match event["event_type"].as_str() {
+            Some(event_type) => *full.by_type.entry(event_type.to_string()).or_default() += 1,
+            None => full.untyped += 1,
+        }
+    }
+
+    let mut reader = JournalReader::open(&journal_path, ReadMode::Strict).unwrap();
+    assert_eq!(reader.scan_types().unwrap(), full);
+    let mut reader = JournalReader::open(&journal_path, ReadMode::Strict).unwrap();
+    assert_eq!(reader.count_frames().unwrap(), 5);
+
+    // A torn final frame is an error in strict mode and ignored in permissive mode.
+    let len = fs::metadata(&journal_path).unwrap().len();
+    fs::OpenOptions::new()
+        .write(true)
+        .open(&journal_path)
+        .unwrap()
+        .set_len(len - 1)
+        .unwrap();
+    let mut reader = JournalReader::open(&journal_path, ReadMode::Strict).unwrap();
+    assert!(matches!(
+        reader.count_frames(),
+        Err(JournalError::TruncatedFrame { .. })
+    ));
+    let mut reader = JournalReader::open(&journal_path, ReadMode::Permissive).unwrap();
+    assert_eq!(reader.count_frames().unwrap(), 5);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_dedup_reader_skips_repeated_events() {
+    let temp_dir = TempDir::new().unwrap();
+    let journal_path = temp_dir.path().join("retried.nrj");
+    let a = make_test_event(&compute_blob_digest(b"a").unwrap().b64);
+    let b = make_test_event(&compute_blob_digest(b"b").unwrap().b64);
+
+    let mut writer = JournalWriter::open(&journal_path, WriteOptions::default()).unwrap();
+    for event in [&a, &a, &a, &b, &a] {
+        writer.append_event(event).unwrap();
+    }
+    writer.finish().unwrap();
+
+    let reader = JournalReader::open(&journal_path, ReadMode::Strict).unwrap();
+    let mut dedup = DedupReader::new(reader);
+    assert_eq!(dedup.read_event().unwrap(), Some(a.clone()));
+    assert_eq!(dedup.read_event().unwrap(), Some(b.clone()));
+    assert!(dedup.read_event().unwrap().is_none());
+    assert_eq!(dedup.duplicates(), 3);
+
+    // Remembering one ID, `a` is forgotten once `b` is read.
+    let reader = JournalReader::open(&journal_path, ReadMode::Strict).unwrap();
+    let mut bounded = DedupReader::with_capacity(reader, 1);
+    let mut read = Vec::new();
+    while let Some(event) = bounded.read_event().unwrap() {
+        read.push(event);
+    }
+    assert_eq!(read, [a.clone(), b, a]);
+    assert_eq!(bounded.duplicates(), 2);
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_async_writer_matches_sync_frames() {
+    use northroot_journal::AsyncJournalWriter;
+
+    let temp_dir = TempDir::new().unwrap();
+    let sync_path = temp_dir.path().join("sync.nrj");
+    let async_path = temp_dir.path().join("async.nrj");
+    let events: Vec<EventJson> = ["a", "b", "c"]
+        .iter()
+        .map(|name| make_test_event(&compute_blob_digest(name.as_bytes()).unwrap().b64))
+        .collect();
+    let options = WriteOptions {
+        write_footer: true,
+        frame_crc: true,
+        index: true,
+        ..WriteOptions::default()
+    };
+
+    let mut writer = JournalWriter::open(&sync_path, options.clone()).unwrap();
+    for event in &events {
+        writer.append_event(event).unwrap();
+    }
+    writer.finish().unwrap();
+
+    // Two sessions, so reopening rebuilds footer and index state.
+    for chunk in [&events[..1], &events[1..]] {
+        let mut writer = AsyncJournalWriter::open(&async_path, options.clone())
+            .await
+            .unwrap();
+        for event in chunk {
+            writer.append_event(event).await.unwrap();
+        }
+        writer.finish().await.unwrap();
+    }
+
+    // The first session leaves a footer mid-file; the final one matches the sync journal's.
+    let mut reader = JournalReader::open(&async_path, ReadMode::Strict).unwrap();
+    let mut read = Vec::new();
+    while let Some(event) = reader.read_event().unwrap() {
+        read.push(event);
+    }
+    assert_eq!(read, events);
+    assert!(reader.is_complete());
+    assert_eq!(
+        reader.last_footer(),
+        JournalReader::open(&sync_path, ReadMode::Strict)
+            .unwrap()
+            .footer()
+            .unwrap()
+            .as_ref()
+    );
+    let index = load_index(&async_path).unwrap().unwrap();
+    assert_eq!(index.len(), 3);
+    assert!(!index.is_stale(&async_path).unwrap());
+
+    // A single session writes the same bytes as the sync writer.
+    let single_path = temp_dir.path().join("single.nrj");
+    let mut writer = AsyncJournalWriter::open(&single_path, options)
+        .await
+        .unwrap();
+    for event in &events {
+        writer.append_event(event).await.unwrap();
+    }
+    writer.finish().await.unwrap();
+    assert_eq!(
+        fs::read(&single_path).unwrap(),
+        fs::read(&sync_path).unwrap()
+    );
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn test_async_appends_from_tasks_read_back_in_order() {
+    use northroot_journal::{AsyncJournalReader, AsyncJournalWriter};
+    use std::sync::Arc;
+    use tokio::sync::Mutex;
+
+    let temp_dir = TempDir::new().unwrap();
+    let journal_path = temp_dir.path().join("tasks.nrj");
+    let options = WriteOptions {
+        write_footer: true,
+        frame_crc: true,
+        ..WriteOptions::default()
+    };
+    let writer = Arc::new(Mutex::new(
+        AsyncJournalWriter::open(&journal_path, options)
+            .await
+            .unwrap(),
+    ));
+
+    // The lock serializes appends; each task's events keep their order.
+    let mut tasks = Vec::new();
+    for task in 0..4 {
+        let writer = Arc::clone(&writer);
+        tasks.push(tokio::spawn(async move {
+            for seq in 0..5 {
+                let mut event = make_test_event(
+                    &compute_blob_digest(format!("{task}-{seq}").as_bytes())
+                        .unwrap()
+                        .b64,
+                );
+                event["task"] = json!(task);
+                event["seq"] = json!(seq);
+                writer.lock().await.append_event(&event).await.unwrap();
+                tokio::task::yield_now().await;
+            }
+        }));
+    }
+    for task in tasks {
+        task.await.unwrap();
+    }
+    let writer = Arc::try_unwrap(writer).ok().unwrap().into_inner();
+    writer.finish().await.unwrap();
+
+    let mut reader = AsyncJournalReader::open(&journal_path, ReadMode::Strict)
+        .await
+        .unwrap();
+    let mut read = Vec::new();
+    while let Some(event) = reader.read_event().await.unwrap() {
+        read.push(event);
+    }
+    assert!(reader.is_complete());
+    assert_eq!(read.len(), 20);
+    for task in 0..4 {
+        let seqs: Vec<_> = read
+            .iter()
+            .filter(|event| event["task"] == json!(task))
+            .map(|event| event["seq"].as_u64().unwrap())
+            .collect();
+        assert_eq!(seqs, [0, 1, 2, 3, 4]);
+    }
+
+    // The sync reader sees the same events in the same order.
+    let mut sync_reader = JournalReader::open(&journal_path, ReadMode::Strict).unwrap();
+    for event in &read {
+        assert_eq!(sync_reader.read_event().unwrap().as_ref(), Some(event));
+    }
+    assert!(sync_reader.read_event().unwrap().is_none());
+}
@@ -3,7 +3,9 @@
 // Core frame logic tested in frame.rs provides Miri UB coverage.
 
 use northroot_journal::frame::MAX_PAYLOAD_SIZE;
-use northroot_journal::{EventJson, JournalReader, JournalWriter, ReadMode, WriteOptions};
+use northroot_journal::{
+    EventJson, JournalError, JournalHeader, JournalReader, JournalWriter, ReadMode, WriteOptions,
+};
 use serde_json::json;
 use std::fs;
 use std::io::{Seek, Write};
@@ -188,6 +190,119 @@ fn test_partial_write_handling() {
     }
 }
 
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_permissive_reader_follows_concurrent_appends() {
+    let temp_dir = TempDir::new().unwrap();
+    let journal_path = temp_dir.path().join("test.nrj");
+    let frames_path = temp_dir.path().join("frames.nrj");
+
+    // Frame bytes for one event, taken from a scratch journal.
+    {
+        let mut writer = JournalWriter::open(&frames_path, WriteOptions::default()).unwrap();
+        writer.append_event(&make_test_event("event3")).unwrap();
+        writer.finish().unwrap();
+    }
+    let frame = fs::read(&frames_path).unwrap()[JournalHeader::HEADER_SIZE..].to_vec();
+
+    let mut writer = JournalWriter::open(&journal_path, WriteOptions::default()).unwrap();
+    writer.append_event(&make_test_event("event1")).unwrap();
+
+    let mut reader = JournalReader::open(&journal_path, ReadMode::Permissive).unwrap();
+    let event1 = reader.read_event().unwrap().unwrap();
+    assert_eq!(event1["event_id"]["b64"], "event1");
+    assert!(reader.read_event().unwrap().is_none());
+
+    writer.append_event(&make_test_event("event2")).unwrap();
+    let event2 = reader.read_event().unwrap().unwrap();
+    assert_eq!(event2["event_id"]["b64"], "event2");
+    writer.finish().unwrap();
+
+    // A frame caught mid-write reads as end-of-file, then in full once done.
+    let mut file = fs::OpenOptions::new()
+        .append(true)
+        .open(&journal_path)
+        .unwrap();
+    file.write_all(&frame[..12]).unwrap();
+    let tail_start = reader.position();
+    assert!(reader.read_event().unwrap().is_none());
+    assert_eq!(reader.position(), tail_start);
+
+    file.write_all(&frame[12..]).unwrap();
+    let event3 = reader.read_event().unwrap().unwrap();
+    assert_eq!(event3["event_id"]["b64"], "event3");
+    assert!(reader.read_event().unwrap().is_none());
+}
+
+/// Writes three events and returns the offset of each frame.
+fn write_three_events(path: &std::path::Path) -> Vec<u64> {
+    let mut writer = JournalWriter::open(path, WriteOptions::default()).unwrap();
+    for id in ["event1", "event2", "event3"] {
+        writer.append_event(&make_test_event(id)).unwrap();
+    }
+    writer.finish().unwrap();
+
+    let mut reader = JournalReader::open(path, ReadMode::Strict).unwrap();
+    let mut offsets = vec![reader.position()];
+    while reader.read_event().unwrap().is_some() {
+        offsets.push(reader.position());
+    }
+    offsets.pop();
+    offsets
+}
+
+fn read_ids(reader: &mut JournalReader) -> Vec<String> {
+    let mut ids = Vec::new();
+    while let Some(event) = reader.read_event().unwrap() {
+        ids.push(event["event_id"]["b64"].as_str().unwrap().to_string());
+    }
+    ids
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_repair_mode_skips_corrupt_middle_frame() {
+    let temp_dir = TempDir::new().unwrap();
+    let journal_path = temp_dir.path().join("test.nrj");
+    let offsets = write_three_events(&journal_path);
+
+    // Corrupt the middle frame's length so it points far past end-of-file.
+    let mut bytes = fs::read(&journal_path).unwrap();
+    bytes[offsets[1] as usize + 7] = 0x00;
+    bytes[offsets[1] as usize + 6] = 0xff;
+    fs::write(&journal_path, &bytes).unwrap();
+
+    let mut strict = JournalReader::open(&journal_path, ReadMode::Strict).unwrap();
+    assert!(strict.read_event().unwrap().is_some());
+    assert!(strict.read_event().is_err());
+
+    let mut reader = JournalReader::open(&journal_path, ReadMode::Repair).unwrap();
+    assert_eq!(read_ids(&mut reader), ["event1", "event3"]);
+    assert_eq!(reader.skipped_frames(), &[offsets[1]]);
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_repair_mode_skips_unparseable_payload() {
+    let temp_dir = TempDir::new().unwrap();
+    let journal_path = temp_dir.path().join("test.nrj");
+    let offsets = write_three_events(&journal_path);
+
+    // Break the middle payload's opening brace.
+    let mut bytes = fs::read(&journal_path).unwrap();
+    bytes[offsets[1] as usize + 8] = b'x';
+    fs::write(&journal_path, &bytes).unwrap();
+
+    let mut reader = JournalReader::open(&journal_path, ReadMode::Repair).unwrap();
+    assert_eq!(read_ids(&mut reader), ["event1", "event3"]);
+    assert_eq!(reader.skipped_frames().len(), 1);
+
+    let mut reader = JournalReader::open(&journal_path, ReadMode::Permissive).unwrap();
+    assert!(reader.read_event().unwrap().is_some());
+    assert!(reader.read_event().is_err());
+    assert!(reader.skipped_frames().is_empty());
+}
+
 #[test]
 #[cfg_attr(miri, ignore)]
 fn test_unknown_frame_kind_skipped() {
@@ -227,3 +342,68 @@ fn test_unknown_frame_kind_skipped() {
         assert!(event2.is_none());
     }
 }
+
+fn write_crc_journal(path: &std::path::Path) {
+    let options = WriteOptions {
+        frame_crc: true,
+        ..Default::default()
+    };
+    let mut writer = JournalWriter::open(path, options).unwrap();
+    writer.append_event(&make_test_event("event1")).unwrap();
+    writer.append_event(&make_test_event("event2")).unwrap();
+    writer.finish().unwrap();
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_frame_crc_round_trip() {
+    let temp_dir = TempDir::new().unwrap();
+    let journal_path = temp_dir.path().join("test.nrj");
+    write_crc_journal(&journal_path);
+
+    // Appending to an existing CRC journal keeps trailers on new frames.
+    let mut writer = JournalWriter::open(&journal_path, WriteOptions::default()).unwrap();
+    writer.append_event(&make_test_event("event3")).unwrap();
+    writer.finish().unwrap();
+
+    let bytes = fs::read(&journal_path).unwrap();
+    assert_eq!(bytes[6], 0x01);
+
+    let mut reader = JournalReader::open(&journal_path, ReadMode::Strict).unwrap();
+    for id in ["event1", "event2", "event3"] {
+        assert_eq!(reader.read_event().unwrap().unwrap()["event_id"]["b64"], id);
+    }
+    assert!(reader.read_event().unwrap().is_none());
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_frame_crc_detects_flipped_payload_byte() {
+    let temp_dir = TempDir::new().unwrap();
+    let journal_path = temp_dir.path().join("test.nrj");
+    write_crc_journal(&journal_path);
+
+    // Flip a byte inside the second event's "event2" string. The payload is
+    // still valid JSON, so only the CRC can catch it.
+    let mut bytes = fs::read(&journal_path).unwrap();
+    let needle = b"event2";
+    let at = bytes
+        .windows(needle.len())
+        .position(|window| window == needle)
+        .unwrap();
+    bytes[at + 5] = b'9';
+    fs::write(&journal_path, &bytes).unwrap();
+
+    let mut reader = JournalReader::open(&journal_path, ReadMode::Strict).unwrap();
+    let first = reader.read_event().unwrap().unwrap();
+    let second_offset = reader.position();
+    assert_eq!(first["event_id"]["b64"], "event1");
+    match reader.read_event() {
+        Err(JournalError::FrameCorrupt { offset }) => assert_eq!(offset, second_offset),
+        other => panic!("expected FrameCorrupt, got {:?}", other),
+    }
+
+    let mut reader = JournalReader::open(&journal_path, ReadMode::Permissive).unwrap();
+    assert!(reader.read_event().unwrap().is_some());
+    assert!(reader.read_event().unwrap().is_none());
+}
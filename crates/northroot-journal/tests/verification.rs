@@ -1,5 +1,8 @@
 use northroot_canonical::{compute_event_id, Canonicalizer, ProfileId};
-use northroot_journal::{verify_event_id, EventObject};
+use northroot_journal::{
+    verify_chain, verify_event_id, verify_temporal_order, ChainVerifier, EventObject,
+    OrderViolation, TemporalOrderChecker,
+};
 use serde_json::json;
 
 fn make_canonicalizer() -> Canonicalizer {
@@ -109,3 +112,117 @@ fn event_object_does_not_validate_domain_semantics() {
 
     assert_eq!(event_object.as_json(), &event);
 }
+
+fn chained_events(count: usize) -> Vec<serde_json::Value> {
+    let canonicalizer = make_canonicalizer();
+    let mut events = Vec::new();
+    let mut prev = None;
+    for index in 0..count {
+        let mut event = json!({
+            "event_type": "test",
+            "event_version": "1",
+            "occurred_at": "2024-01-01T00:00:00Z",
+            "principal_id": "service:test",
+            "canonical_profile_id": "northroot-canonical-v1",
+            "data": index.to_string()
+        });
+        if let Some(prev_id) = &prev {
+            event["prev_event_id"] = serde_json::to_value(prev_id).unwrap();
+        }
+        let event_id = compute_event_id(&event, &canonicalizer).unwrap();
+        event["event_id"] = serde_json::to_value(&event_id).unwrap();
+        prev = Some(event_id);
+        events.push(event);
+    }
+    events
+}
+
+#[test]
+fn verify_chain_accepts_linked_events() {
+    let canonicalizer = make_canonicalizer();
+    let events = chained_events(3);
+
+    assert!(verify_chain(&events, &canonicalizer).unwrap().is_none());
+}
+
+#[test]
+fn verify_chain_reports_spliced_event() {
+    let canonicalizer = make_canonicalizer();
+    let mut events = chained_events(3);
+    events.remove(1);
+
+    let chain_break = verify_chain(&events, &canonicalizer).unwrap().unwrap();
+    assert_eq!(chain_break.index, 1);
+    let first_id = compute_event_id(&events[0], &canonicalizer).unwrap();
+    assert_eq!(chain_break.expected, Some(first_id));
+    assert_ne!(chain_break.found, chain_break.expected);
+}
+
+#[test]
+fn verify_chain_requires_genesis_to_match() {
+    let canonicalizer = make_canonicalizer();
+    let events = chained_events(3);
+
+    let chain_break = verify_chain(&events[1..], &canonicalizer).unwrap().unwrap();
+    assert_eq!(chain_break.index, 0);
+    assert_eq!(chain_break.expected, None);
+
+    let genesis = compute_event_id(&events[0], &canonicalizer).unwrap();
+    let mut chain = ChainVerifier::with_genesis(&canonicalizer, genesis);
+    for event in &events[1..] {
+        assert!(chain.check(event).unwrap().is_none());
+    }
+}
+
+fn events_at(times: &[&str]) -> Vec<serde_json::Value> {
+    times
+        .iter()
+        .map(|time| json!({"event_type": "test", "occurred_at": time}))
+        .collect()
+}
+
+#[test]
+fn verify_temporal_order_accepts_non_decreasing_events() {
+    let events = events_at(&[
+        "2024-01-01T00:00:00Z",
+        "2024-01-01T00:00:00Z",
+        "2024-01-01T00:00:00.500Z",
+        "2024-01-02T00:00:00Z",
+    ]);
+
+    assert!(verify_temporal_order(&events, 0).is_empty());
+}
+
+#[test]
+fn verify_temporal_order_applies_tolerance() {
+    let events = events_at(&[
+        "2024-01-01T00:00:01Z",
+        "2024-01-01T00:00:00.750Z",
+        "2024-01-01T00:00:00Z",
+    ]);
+
+    assert_eq!(verify_temporal_order(&events, 0), vec![1, 2]);
+    assert_eq!(verify_temporal_order(&events, 250), vec![2]);
+    assert!(verify_temporal_order(&events, 750).is_empty());
+
+    let mut checker = TemporalOrderChecker::new(250);
+    assert_eq!(checker.check(&events[0]), None);
+    assert_eq!(checker.check(&events[1]), None);
+    assert_eq!(
+        checker.check(&events[2]),
+        Some(OrderViolation::Regressed { by_ms: 750 })
+    );
+}
+
+#[test]
+fn verify_temporal_order_rejects_missing_or_invalid_timestamps() {
+    let mut events = events_at(&["2024-01-01T00:00:00Z", "not a time", "2024-01-01T00:00:01Z"]);
+    events.push(json!({"event_type": "test"}));
+
+    assert_eq!(verify_temporal_order(&events, 0), vec![1, 3]);
+    let mut checker = TemporalOrderChecker::new(0);
+    assert_eq!(
+        checker.check(&events[3]),
+        Some(OrderViolation::InvalidTimestamp)
+    );
+}